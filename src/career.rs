@@ -0,0 +1,168 @@
+use crate::error::{Error, Result};
+use crate::history::Season;
+use crate::table::{Align, Cell, Table};
+use crate::utils::{find_driver_name, RaceStatus};
+use crate::CURR_YEAR;
+
+#[derive(Default)]
+struct SeasonStats {
+    teams: Vec<String>,
+    starts: usize,
+    wins: usize,
+    podiums: usize,
+    poles: usize,
+    points: usize,
+    // points are summed from race results when standings are not available
+    race_points_only: bool,
+    dnfs: usize,
+    championship: Option<usize>,
+}
+
+impl SeasonStats {
+    fn row(&self, label: Cell, teams: String, championship: String) -> Vec<Cell> {
+        let points = if self.race_points_only {
+            format!("{}*", self.points)
+        } else {
+            self.points.to_string()
+        };
+        vec![
+            label,
+            teams.into(),
+            self.starts.into(),
+            self.wins.into(),
+            self.podiums.into(),
+            self.poles.into(),
+            points.into(),
+            self.dnfs.into(),
+            championship.into(),
+        ]
+    }
+
+    fn add(&mut self, other: &SeasonStats) {
+        self.starts += other.starts;
+        self.wins += other.wins;
+        self.podiums += other.podiums;
+        self.poles += other.poles;
+        self.points += other.points;
+        self.race_points_only |= other.race_points_only;
+        self.dnfs += other.dnfs;
+    }
+}
+
+fn season_stats(name: &str, season: &Season) -> Option<SeasonStats> {
+    let mut stats = SeasonStats::default();
+    let mut race_points = 0;
+    let mut raced = false;
+    for race in &season.races {
+        let Some(result) = race.results.iter().find(|d| d.name == name) else {
+            continue;
+        };
+        raced = true;
+        if let Some(team) = &result.team {
+            if !stats.teams.contains(team) {
+                stats.teams.push(team.clone());
+            }
+        }
+        race_points += result.points;
+        match result.status {
+            Some(RaceStatus::DidNotStart) => continue,
+            Some(RaceStatus::Retired | RaceStatus::Disqualified) => stats.dnfs += 1,
+            // caches created by older versions only have position `0`
+            None if result.position == 0 => stats.dnfs += 1,
+            _ => {}
+        }
+        stats.starts += 1;
+        match result.position {
+            1 => {
+                stats.wins += 1;
+                stats.podiums += 1;
+            }
+            2 | 3 => stats.podiums += 1,
+            _ => {}
+        }
+    }
+    if !raced {
+        return None;
+    }
+
+    stats.poles = season
+        .qualifying
+        .iter()
+        .filter(|q| q.results.iter().any(|d| d.position == 1 && d.name == name))
+        .count();
+    match season.driver_standings.iter().find(|d| d.name == name) {
+        Some(standing) => {
+            stats.points = standing.points;
+            stats.championship = Some(standing.position);
+        }
+        None => {
+            stats.points = race_points;
+            stats.race_points_only = true;
+        }
+    }
+    Some(stats)
+}
+
+pub fn pp_driver_career(query: &str, seasons: &[Season], output: &mut String) -> Result<()> {
+    let names = seasons
+        .iter()
+        .flat_map(|s| s.races.iter())
+        .flat_map(|r| r.results.iter().map(|d| d.name.as_str()));
+    let name = find_driver_name(names, query)?;
+
+    let (Some(first), Some(last)) = (seasons.first(), seasons.last()) else {
+        return Err(Error::UnknownDriver(query.to_owned()));
+    };
+    let mut table = Table::new()
+        .title(format!("{} - Career {} - {}", name, first.year, last.year))
+        .column("Season", Align::Left)
+        .column("Team", Align::Left)
+        .column("Starts", Align::Right)
+        .column("Wins", Align::Right)
+        .column("Podiums", Align::Right)
+        .column("Poles", Align::Right)
+        .column("Points", Align::Right)
+        .column("DNF", Align::Right)
+        .column("Champ", Align::Right);
+
+    let mut total = SeasonStats::default();
+    // best championship position & the seasons it was achieved in. Standings of
+    // this season are still changing, only finished seasons count
+    let mut best: Option<(usize, Vec<i32>)> = None;
+    for season in seasons {
+        let Some(stats) = season_stats(&name, season) else {
+            continue;
+        };
+        if let Some(position) = stats.championship.filter(|_| season.year < *CURR_YEAR) {
+            match &mut best {
+                Some((best_pos, years)) if *best_pos == position => years.push(season.year),
+                Some((best_pos, _)) if *best_pos < position => {}
+                _ => best = Some((position, vec![season.year])),
+            }
+        }
+        total.add(&stats);
+        let championship = stats
+            .championship
+            .map(|p| format!("P{p}"))
+            .unwrap_or("-".to_owned());
+        table.add_row(stats.row(season.year.into(), stats.teams.join(", "), championship));
+    }
+    let best_pp = match &best {
+        Some((position, _)) => format!("P{position}"),
+        None => "-".to_owned(),
+    };
+    table.add_row(total.row("Total".into(), String::new(), best_pp));
+
+    if let Some((position, years)) = best {
+        let years = years.iter().map(|y| y.to_string()).collect::<Vec<_>>();
+        table.add_footer(format!(
+            "Best championship finish: P{} ({})",
+            position,
+            years.join(", ")
+        ));
+    }
+    if total.race_points_only {
+        table.add_footer("* standings not available, sum of Grand Prix points without sprints");
+    }
+    table.render(output)
+}
@@ -0,0 +1,681 @@
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc, Weekday};
+
+use crate::error::{Error, Result};
+use crate::schedule::{GrandPrix, STR_FMT};
+use crate::table::{Align, Table};
+
+// Daylight saving rules of the track's timezone, offset is one hour ahead while active
+#[derive(Debug, Clone, Copy)]
+pub enum Dst {
+    None,
+    // last Sunday of March to last Sunday of October
+    Europe,
+    // second Sunday of March to first Sunday of November
+    NorthAmerica,
+    // first Sunday of October to first Sunday of April
+    SouthEastAustralia,
+}
+
+#[derive(Debug)]
+pub struct LapRecord {
+    pub time: &'static str,
+    pub driver: &'static str,
+    pub year: i32,
+}
+
+#[derive(Debug)]
+pub struct Circuit {
+    pub name: &'static str,
+    // Grand Prix names & locations as used by the schedule source & results pages
+    pub aliases: &'static [&'static str],
+    pub country: &'static str,
+    pub length_km: f64,
+    pub laps: u32,
+    // official distance, slightly differs from laps times lap length as start &
+    // finish lines are not always at the same place
+    pub race_distance_km: f64,
+    pub timezone: &'static str,
+    // standard time offset from UTC
+    utc_offset_mins: i32,
+    dst: Dst,
+    pub first_gp: i32,
+    // Years the Grand Prix named in `aliases` was held here, in other years it
+    // was held at another track, e.g. the British Grand Prix at Brands Hatch
+    gp_years: &'static [(i32, i32)],
+    pub lap_record: Option<LapRecord>,
+}
+
+const fn record(time: &'static str, driver: &'static str, year: i32) -> Option<LapRecord> {
+    Some(LapRecord { time, driver, year })
+}
+
+// End of `gp_years` for Grand Prix still held at the circuit
+const ONGOING: i32 = i32::MAX;
+
+pub const CIRCUITS: [Circuit; 26] = [
+    Circuit {
+        name: "Bahrain International Circuit",
+        aliases: &["Bahrain", "Sakhir"],
+        country: "Bahrain",
+        length_km: 5.412,
+        laps: 57,
+        race_distance_km: 308.238,
+        timezone: "Asia/Bahrain",
+        utc_offset_mins: 180,
+        dst: Dst::None,
+        first_gp: 2004,
+        gp_years: &[(2004, ONGOING)],
+        lap_record: record("1:31.447", "Pedro de la Rosa", 2005),
+    },
+    Circuit {
+        name: "Jeddah Corniche Circuit",
+        aliases: &["Saudi Arabian", "Saudi Arabia", "Jeddah"],
+        country: "Saudi Arabia",
+        length_km: 6.174,
+        laps: 50,
+        race_distance_km: 308.450,
+        timezone: "Asia/Riyadh",
+        utc_offset_mins: 180,
+        dst: Dst::None,
+        first_gp: 2021,
+        gp_years: &[(2021, ONGOING)],
+        lap_record: record("1:30.734", "Lewis Hamilton", 2021),
+    },
+    Circuit {
+        name: "Albert Park Circuit",
+        aliases: &["Australian", "Australia", "Melbourne"],
+        country: "Australia",
+        length_km: 5.278,
+        laps: 58,
+        race_distance_km: 306.124,
+        timezone: "Australia/Melbourne",
+        utc_offset_mins: 600,
+        dst: Dst::SouthEastAustralia,
+        first_gp: 1996,
+        gp_years: &[(1996, ONGOING)],
+        lap_record: record("1:19.813", "Charles Leclerc", 2024),
+    },
+    Circuit {
+        name: "Suzuka International Racing Course",
+        aliases: &["Japanese", "Japan", "Suzuka"],
+        country: "Japan",
+        length_km: 5.807,
+        laps: 53,
+        race_distance_km: 307.471,
+        timezone: "Asia/Tokyo",
+        utc_offset_mins: 540,
+        dst: Dst::None,
+        first_gp: 1987,
+        gp_years: &[(1987, 2006), (2009, ONGOING)],
+        lap_record: record("1:30.983", "Lewis Hamilton", 2019),
+    },
+    Circuit {
+        name: "Shanghai International Circuit",
+        aliases: &["Chinese", "China", "Shanghai"],
+        country: "China",
+        length_km: 5.451,
+        laps: 56,
+        race_distance_km: 305.066,
+        timezone: "Asia/Shanghai",
+        utc_offset_mins: 480,
+        dst: Dst::None,
+        first_gp: 2004,
+        gp_years: &[(2004, ONGOING)],
+        lap_record: record("1:32.238", "Michael Schumacher", 2004),
+    },
+    Circuit {
+        name: "Miami International Autodrome",
+        aliases: &["Miami"],
+        country: "United States",
+        length_km: 5.412,
+        laps: 57,
+        race_distance_km: 308.326,
+        timezone: "America/New_York",
+        utc_offset_mins: -300,
+        dst: Dst::NorthAmerica,
+        first_gp: 2022,
+        gp_years: &[(2022, ONGOING)],
+        lap_record: record("1:29.708", "Max Verstappen", 2023),
+    },
+    Circuit {
+        name: "Autodromo Enzo e Dino Ferrari",
+        aliases: &["Emilia Romagna", "Emilia-Romagna", "San Marino", "Imola"],
+        country: "Italy",
+        length_km: 4.909,
+        laps: 63,
+        race_distance_km: 309.049,
+        timezone: "Europe/Rome",
+        utc_offset_mins: 60,
+        dst: Dst::Europe,
+        first_gp: 1980,
+        gp_years: &[(1981, 2006), (2020, ONGOING)],
+        lap_record: record("1:15.484", "Lewis Hamilton", 2020),
+    },
+    Circuit {
+        name: "Circuit de Monaco",
+        aliases: &["Monaco", "Monte Carlo"],
+        country: "Monaco",
+        length_km: 3.337,
+        laps: 78,
+        race_distance_km: 260.286,
+        timezone: "Europe/Monaco",
+        utc_offset_mins: 60,
+        dst: Dst::Europe,
+        first_gp: 1950,
+        gp_years: &[(1950, ONGOING)],
+        lap_record: record("1:12.909", "Lewis Hamilton", 2021),
+    },
+    Circuit {
+        name: "Circuit Gilles Villeneuve",
+        aliases: &["Canadian", "Canada", "Montreal", "Montréal"],
+        country: "Canada",
+        length_km: 4.361,
+        laps: 70,
+        race_distance_km: 305.270,
+        timezone: "America/Toronto",
+        utc_offset_mins: -300,
+        dst: Dst::NorthAmerica,
+        first_gp: 1978,
+        gp_years: &[(1978, ONGOING)],
+        lap_record: record("1:13.078", "Valtteri Bottas", 2019),
+    },
+    Circuit {
+        name: "Madring",
+        aliases: &["Madrid", "Spanish", "Spain"],
+        country: "Spain",
+        length_km: 5.474,
+        laps: 57,
+        race_distance_km: 312.018,
+        timezone: "Europe/Madrid",
+        utc_offset_mins: 60,
+        dst: Dst::Europe,
+        first_gp: 2026,
+        gp_years: &[(2026, ONGOING)],
+        lap_record: None,
+    },
+    Circuit {
+        name: "Circuit de Barcelona-Catalunya",
+        aliases: &[
+            "Spanish",
+            "Spain",
+            "Barcelona",
+            "Barcelona-Catalunya",
+            "Catalunya",
+        ],
+        country: "Spain",
+        length_km: 4.657,
+        laps: 66,
+        race_distance_km: 307.236,
+        timezone: "Europe/Madrid",
+        utc_offset_mins: 60,
+        dst: Dst::Europe,
+        first_gp: 1991,
+        gp_years: &[(1991, ONGOING)],
+        lap_record: record("1:16.330", "Max Verstappen", 2023),
+    },
+    Circuit {
+        name: "Red Bull Ring",
+        aliases: &["Austrian", "Austria", "Styria", "Spielberg"],
+        country: "Austria",
+        length_km: 4.318,
+        laps: 71,
+        race_distance_km: 306.452,
+        timezone: "Europe/Vienna",
+        utc_offset_mins: 60,
+        dst: Dst::Europe,
+        first_gp: 1970,
+        gp_years: &[(1970, ONGOING)],
+        lap_record: record("1:05.619", "Carlos Sainz", 2020),
+    },
+    Circuit {
+        name: "Silverstone Circuit",
+        aliases: &[
+            "British",
+            "Great Britain",
+            "70th Anniversary",
+            "Silverstone",
+        ],
+        country: "United Kingdom",
+        length_km: 5.891,
+        laps: 52,
+        race_distance_km: 306.198,
+        timezone: "Europe/London",
+        utc_offset_mins: 0,
+        dst: Dst::Europe,
+        first_gp: 1950,
+        gp_years: &[
+            (1950, 1954),
+            (1956, 1956),
+            (1958, 1958),
+            (1960, 1960),
+            (1963, 1963),
+            (1965, 1965),
+            (1967, 1967),
+            (1969, 1969),
+            (1971, 1971),
+            (1973, 1973),
+            (1975, 1975),
+            (1977, 1977),
+            (1979, 1979),
+            (1981, 1981),
+            (1983, 1983),
+            (1985, 1985),
+            (1987, ONGOING),
+        ],
+        lap_record: record("1:27.097", "Max Verstappen", 2020),
+    },
+    Circuit {
+        name: "Hungaroring",
+        aliases: &["Hungarian", "Hungary", "Budapest"],
+        country: "Hungary",
+        length_km: 4.381,
+        laps: 70,
+        race_distance_km: 306.630,
+        timezone: "Europe/Budapest",
+        utc_offset_mins: 60,
+        dst: Dst::Europe,
+        first_gp: 1986,
+        gp_years: &[(1986, ONGOING)],
+        lap_record: record("1:16.627", "Lewis Hamilton", 2020),
+    },
+    Circuit {
+        name: "Circuit de Spa-Francorchamps",
+        aliases: &["Belgian", "Belgium", "Spa-Francorchamps", "Spa"],
+        country: "Belgium",
+        length_km: 7.004,
+        laps: 44,
+        race_distance_km: 308.052,
+        timezone: "Europe/Brussels",
+        utc_offset_mins: 60,
+        dst: Dst::Europe,
+        first_gp: 1950,
+        gp_years: &[(1950, 1970), (1983, 1983), (1985, ONGOING)],
+        lap_record: record("1:46.286", "Valtteri Bottas", 2018),
+    },
+    Circuit {
+        name: "Circuit Zandvoort",
+        aliases: &["Dutch", "Netherlands", "Zandvoort"],
+        country: "Netherlands",
+        length_km: 4.259,
+        laps: 72,
+        race_distance_km: 306.587,
+        timezone: "Europe/Amsterdam",
+        utc_offset_mins: 60,
+        dst: Dst::Europe,
+        first_gp: 1952,
+        gp_years: &[(1952, ONGOING)],
+        lap_record: record("1:11.097", "Lewis Hamilton", 2021),
+    },
+    Circuit {
+        name: "Autodromo Nazionale Monza",
+        aliases: &["Italian", "Italy", "Monza"],
+        country: "Italy",
+        length_km: 5.793,
+        laps: 53,
+        race_distance_km: 306.720,
+        timezone: "Europe/Rome",
+        utc_offset_mins: 60,
+        dst: Dst::Europe,
+        first_gp: 1950,
+        gp_years: &[(1950, 1979), (1981, ONGOING)],
+        lap_record: record("1:21.046", "Rubens Barrichello", 2004),
+    },
+    Circuit {
+        name: "Baku City Circuit",
+        aliases: &["Azerbaijan", "Baku"],
+        country: "Azerbaijan",
+        length_km: 6.003,
+        laps: 51,
+        race_distance_km: 306.049,
+        timezone: "Asia/Baku",
+        utc_offset_mins: 240,
+        dst: Dst::None,
+        first_gp: 2016,
+        gp_years: &[(2016, ONGOING)],
+        lap_record: record("1:43.009", "Charles Leclerc", 2019),
+    },
+    Circuit {
+        name: "Marina Bay Street Circuit",
+        aliases: &["Singapore", "Marina Bay"],
+        country: "Singapore",
+        length_km: 4.940,
+        laps: 62,
+        race_distance_km: 306.143,
+        timezone: "Asia/Singapore",
+        utc_offset_mins: 480,
+        dst: Dst::None,
+        first_gp: 2008,
+        gp_years: &[(2008, ONGOING)],
+        lap_record: record("1:34.486", "Daniel Ricciardo", 2024),
+    },
+    Circuit {
+        name: "Circuit of the Americas",
+        aliases: &["United States", "Austin"],
+        country: "United States",
+        length_km: 5.513,
+        laps: 56,
+        race_distance_km: 308.405,
+        timezone: "America/Chicago",
+        utc_offset_mins: -360,
+        dst: Dst::NorthAmerica,
+        first_gp: 2012,
+        gp_years: &[(2012, ONGOING)],
+        lap_record: record("1:36.169", "Charles Leclerc", 2019),
+    },
+    Circuit {
+        name: "Autódromo Hermanos Rodríguez",
+        aliases: &["Mexico City", "Mexican", "Mexico"],
+        country: "Mexico",
+        length_km: 4.304,
+        laps: 71,
+        race_distance_km: 305.354,
+        timezone: "America/Mexico_City",
+        utc_offset_mins: -360,
+        dst: Dst::None,
+        first_gp: 1963,
+        gp_years: &[(1963, ONGOING)],
+        lap_record: record("1:17.774", "Valtteri Bottas", 2021),
+    },
+    Circuit {
+        name: "Autódromo José Carlos Pace",
+        aliases: &[
+            "São Paulo",
+            "Sao Paulo",
+            "Brazilian",
+            "Brazil",
+            "Interlagos",
+        ],
+        country: "Brazil",
+        length_km: 4.309,
+        laps: 71,
+        race_distance_km: 305.879,
+        timezone: "America/Sao_Paulo",
+        utc_offset_mins: -180,
+        dst: Dst::None,
+        first_gp: 1973,
+        gp_years: &[(1973, 1977), (1979, 1980), (1990, ONGOING)],
+        lap_record: record("1:10.540", "Valtteri Bottas", 2018),
+    },
+    Circuit {
+        name: "Las Vegas Strip Circuit",
+        aliases: &["Las Vegas"],
+        country: "United States",
+        length_km: 6.201,
+        laps: 50,
+        race_distance_km: 309.958,
+        timezone: "America/Los_Angeles",
+        utc_offset_mins: -480,
+        dst: Dst::NorthAmerica,
+        first_gp: 2023,
+        gp_years: &[(2023, ONGOING)],
+        lap_record: record("1:35.490", "Oscar Piastri", 2023),
+    },
+    Circuit {
+        name: "Lusail International Circuit",
+        aliases: &["Qatar", "Lusail"],
+        country: "Qatar",
+        length_km: 5.419,
+        laps: 57,
+        race_distance_km: 308.611,
+        timezone: "Asia/Qatar",
+        utc_offset_mins: 180,
+        dst: Dst::None,
+        first_gp: 2021,
+        gp_years: &[(2021, ONGOING)],
+        lap_record: record("1:24.319", "Max Verstappen", 2023),
+    },
+    Circuit {
+        name: "Yas Marina Circuit",
+        aliases: &["Abu Dhabi", "Yas Marina", "Yas Island"],
+        country: "United Arab Emirates",
+        length_km: 5.281,
+        laps: 58,
+        race_distance_km: 306.183,
+        timezone: "Asia/Dubai",
+        utc_offset_mins: 240,
+        dst: Dst::None,
+        first_gp: 2009,
+        gp_years: &[(2009, ONGOING)],
+        lap_record: record("1:26.103", "Max Verstappen", 2021),
+    },
+    Circuit {
+        name: "Istanbul Park",
+        aliases: &["Turkish", "Turkey", "Istanbul"],
+        country: "Turkey",
+        length_km: 5.338,
+        laps: 58,
+        race_distance_km: 309.396,
+        timezone: "Europe/Istanbul",
+        utc_offset_mins: 180,
+        dst: Dst::None,
+        first_gp: 2005,
+        gp_years: &[(2005, ONGOING)],
+        lap_record: record("1:24.770", "Juan Pablo Montoya", 2005),
+    },
+];
+
+fn nth_sunday(year: i32, month: u32, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, n).unwrap_or_default()
+}
+
+fn last_sunday(year: i32, month: u32) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, 5)
+        .unwrap_or_else(|| nth_sunday(year, month, 4))
+}
+
+impl Circuit {
+    // Transitions are approximated to the day, sessions never run overnight
+    fn is_dst(&self, dt: DateTime<Utc>) -> bool {
+        let year = dt.year();
+        let date = dt.date_naive();
+        match self.dst {
+            Dst::None => false,
+            Dst::Europe => date >= last_sunday(year, 3) && date < last_sunday(year, 10),
+            Dst::NorthAmerica => date >= nth_sunday(year, 3, 2) && date < nth_sunday(year, 11, 1),
+            Dst::SouthEastAustralia => {
+                date < nth_sunday(year, 4, 1) || date >= nth_sunday(year, 10, 1)
+            }
+        }
+    }
+
+    pub fn utc_offset(&self, dt: DateTime<Utc>) -> FixedOffset {
+        let dst_mins = if self.is_dst(dt) { 60 } else { 0 };
+        FixedOffset::east_opt((self.utc_offset_mins + dst_mins) * 60)
+            .unwrap_or(FixedOffset::east_opt(0).expect("UTC offset is valid"))
+    }
+
+    pub fn track_time<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> DateTime<FixedOffset> {
+        let utc = dt.with_timezone(&Utc);
+        utc.with_timezone(&self.utc_offset(utc))
+    }
+
+    fn pp_utc_offset(&self, dt: DateTime<Utc>) -> String {
+        let offset = self.utc_offset(dt).local_minus_utc() / 60;
+        let sign = if offset < 0 { '-' } else { '+' };
+        let offset = offset.abs();
+        if offset % 60 == 0 {
+            format!("UTC{sign}{}", offset / 60)
+        } else {
+            format!("UTC{sign}{}:{:02}", offset / 60, offset % 60)
+        }
+    }
+}
+
+// Finds circuit by Grand Prix name or location as used in the schedule
+pub fn find_circuit(gp_name: &str, location: &str) -> Option<&'static Circuit> {
+    let matches = |query: &str| {
+        let query = query.trim_end_matches("Grand Prix").trim().to_lowercase();
+        CIRCUITS
+            .iter()
+            .find(|c| c.aliases.iter().any(|a| a.to_lowercase() == query))
+    };
+    matches(location).or_else(|| matches(gp_name))
+}
+
+impl Circuit {
+    pub fn hosted_gp(&self, year: i32) -> bool {
+        self.gp_years
+            .iter()
+            .any(|(from, to)| (*from..=*to).contains(&year))
+    }
+}
+
+// Circuit that held `gp_name` in `year`. When a Grand Prix moved, the old & new
+// venue share the alias, the one holding it most recently wins, e.g. the Spanish
+// Grand Prix is at Barcelona until 2025 and at Madring from 2026
+pub fn find_circuit_in(gp_name: &str, year: i32) -> Option<&'static Circuit> {
+    let query = gp_name.trim_end_matches("Grand Prix").trim().to_lowercase();
+    CIRCUITS
+        .iter()
+        .filter(|c| c.aliases.iter().any(|a| a.to_lowercase() == query))
+        .filter_map(|c| {
+            c.gp_years
+                .iter()
+                .find(|(from, to)| (*from..=*to).contains(&year))
+                .map(|(from, _)| (c, from))
+        })
+        .max_by_key(|(_, from)| **from)
+        .map(|(c, _)| c)
+}
+
+// Case insensitive search over circuit names, countries & aliases. An exact match
+// is preferred, otherwise the only circuit containing `query`, e.g. `austr` matches
+// both Albert Park & Red Bull Ring
+pub fn search_circuit(query: &str) -> Result<&'static Circuit> {
+    let query_lower = query.to_lowercase();
+    let names = |c: &'static Circuit| {
+        [c.name, c.country]
+            .into_iter()
+            .chain(c.aliases.iter().copied())
+    };
+    let exact = CIRCUITS
+        .iter()
+        .filter(|c| names(c).any(|n| n.to_lowercase() == query_lower))
+        .collect::<Vec<_>>();
+    let matches = if exact.is_empty() {
+        CIRCUITS
+            .iter()
+            .filter(|c| names(c).any(|n| n.to_lowercase().contains(&query_lower)))
+            .collect()
+    } else {
+        exact
+    };
+    match matches.as_slice() {
+        [] => Err(Error::UnknownCircuit(query.to_owned())),
+        [circuit] => Ok(circuit),
+        _ => Err(Error::AmbiguousName(
+            query.to_owned(),
+            matches.iter().map(|c| c.name.to_owned()).collect(),
+        )),
+    }
+}
+
+// `gp` is used to show session times in both local & track time
+pub fn pp_circuit(circuit: &Circuit, gp: Option<&GrandPrix>, output: &mut String) -> Result<()> {
+    let race_dt = gp
+        .map(|gp| gp.gp_start_dt().with_timezone(&Utc))
+        .unwrap_or(Utc::now());
+    let mut table = Table::new()
+        .title(circuit.name)
+        .column("", Align::Left)
+        .column("", Align::Left);
+    table.add_row(vec!["Country".into(), circuit.country.into()]);
+    table.add_row(vec![
+        "Lap length".into(),
+        format!("{:.3} km", circuit.length_km).into(),
+    ]);
+    table.add_row(vec!["Race laps".into(), circuit.laps.into()]);
+    table.add_row(vec![
+        "Race distance".into(),
+        format!("{:.3} km", circuit.race_distance_km).into(),
+    ]);
+    table.add_row(vec![
+        "Timezone".into(),
+        format!("{} ({})", circuit.timezone, circuit.pp_utc_offset(race_dt)).into(),
+    ]);
+    table.add_row(vec!["First Grand Prix".into(), circuit.first_gp.into()]);
+    table.add_row(vec![
+        "Lap record".into(),
+        match &circuit.lap_record {
+            Some(record) => format!("{} {} ({})", record.time, record.driver, record.year),
+            None => "-".to_owned(),
+        }
+        .into(),
+    ]);
+    table.render(output)?;
+
+    let Some(gp) = gp else {
+        return Ok(());
+    };
+    let mut sessions = Table::new()
+        .title(gp.gp_name())
+        .column("Session", Align::Left)
+        .column("Local time", Align::Left)
+        .column("Track time", Align::Left);
+    for (name, dt) in gp.sessions() {
+        sessions.add_row(vec![
+            name.into(),
+            dt.format(STR_FMT).into(),
+            circuit.track_time(&dt).format(STR_FMT).into(),
+        ]);
+    }
+    output.push('\n');
+    sessions.render(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name(circuit: Option<&Circuit>) -> &str {
+        circuit.map(|c| c.name).unwrap_or("-")
+    }
+
+    #[test]
+    fn grand_prix_venue_depends_on_year() {
+        assert_eq!(
+            name(find_circuit_in("British Grand Prix", 1987)),
+            "Silverstone Circuit"
+        );
+        // Brands Hatch, Zolder, Fuji & Jacarepaguá aren't listed
+        assert_eq!(name(find_circuit_in("British Grand Prix", 1986)), "-");
+        assert_eq!(name(find_circuit_in("Belgian Grand Prix", 1982)), "-");
+        assert_eq!(name(find_circuit_in("Japanese Grand Prix", 2008)), "-");
+        assert_eq!(name(find_circuit_in("Brazilian Grand Prix", 1985)), "-");
+        // Italian Grand Prix at Imola
+        assert_eq!(name(find_circuit_in("Italian Grand Prix", 1980)), "-");
+        assert_eq!(
+            name(find_circuit_in("Italian Grand Prix", 1981)),
+            "Autodromo Nazionale Monza"
+        );
+        assert_eq!(
+            name(find_circuit_in("Spanish Grand Prix", 2025)),
+            "Circuit de Barcelona-Catalunya"
+        );
+        assert_eq!(name(find_circuit_in("Spanish Grand Prix", 2026)), "Madring");
+    }
+
+    #[test]
+    fn search_prefers_exact_and_reports_ambiguity() {
+        assert_eq!(
+            search_circuit("monza").unwrap().name,
+            "Autodromo Nazionale Monza"
+        );
+        // exact alias wins over `Spanish` & `Spain` containing it
+        assert_eq!(
+            search_circuit("Spa").unwrap().name,
+            "Circuit de Spa-Francorchamps"
+        );
+        match search_circuit("austr") {
+            Err(Error::AmbiguousName(query, names)) => {
+                assert_eq!(query, "austr");
+                assert_eq!(names, vec!["Albert Park Circuit", "Red Bull Ring"]);
+            }
+            other => panic!("expected ambiguity, got {other:?}"),
+        }
+        assert!(matches!(
+            search_circuit("nürburgring"),
+            Err(Error::UnknownCircuit(_))
+        ));
+    }
+}
@@ -0,0 +1,140 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use crate::error::Result;
+use crate::laptime::{pp_gap, LapTime};
+use crate::quali::CompletedQualifying;
+use crate::table::{team_color, Align, Cell, Table};
+use crate::utils::QualiSession;
+
+fn pp_time(time: Option<LapTime>) -> String {
+    time.map(|t| t.to_string()).unwrap_or("-".to_owned())
+}
+
+pub fn pp_season_cutoffs(qualifying: &[CompletedQualifying], output: &mut String) -> Result<()> {
+    let mut table = Table::new()
+        .title("QUALIFYING CUT-OFFS")
+        .column("#", Align::Right)
+        .column("Grand Prix", Align::Left)
+        .column("Q1 Cut-off", Align::Center)
+        .column("Q2 Cut-off", Align::Center)
+        .column("Pole", Align::Center);
+    for quali in qualifying {
+        table.add_row(vec![
+            quali.round.into(),
+            quali.gp_name.as_str().into(),
+            pp_time(quali.q1_cutoff()).into(),
+            pp_time(quali.q2_cutoff()).into(),
+            pp_time(quali.pole_time()).into(),
+        ]);
+    }
+    table.add_footer("Cut-off is the slowest time which made it to the next session");
+    table.render(output)
+}
+
+pub fn pp_round_eliminations(quali: &CompletedQualifying, output: &mut String) -> Result<()> {
+    let q1_cutoff = quali.q1_cutoff();
+    let q2_cutoff = quali.q2_cutoff();
+
+    let mut table = Table::new()
+        .title(format!("{} - Eliminations", quali.gp_name))
+        .column("Pos", Align::Right)
+        .column("Driver", Align::Left)
+        .column("Team", Align::Left)
+        .column("Out", Align::Center)
+        .column("Time", Align::Center)
+        .column("Missed By", Align::Right);
+    for driver in &quali.results {
+        let Some(session) = quali.eliminated_in(driver) else {
+            continue;
+        };
+        let cutoff = match session {
+            QualiSession::Q1 => q1_cutoff,
+            _ => q2_cutoff,
+        };
+        let time = driver.time_in(session);
+        let missed_by = match (time, cutoff) {
+            (Some(time), Some(cutoff)) => pp_gap(time.gap_to(&cutoff)),
+            (None, _) => "No time".to_owned(),
+            _ => "-".to_owned(),
+        };
+        let team = driver.team.as_deref().unwrap_or_default();
+        table.add_row(vec![
+            driver.position.into(),
+            driver.name.as_str().into(),
+            Cell::colored(team, team_color(team)),
+            session.name().into(),
+            pp_time(time).into(),
+            missed_by.into(),
+        ]);
+    }
+    table.add_footer(format!("Q1 cut-off: {}", pp_time(q1_cutoff)));
+    table.add_footer(format!("Q2 cut-off: {}", pp_time(q2_cutoff)));
+    table.render(output)
+}
+
+#[derive(Default)]
+struct Progression {
+    entries: usize,
+    reached_q2: usize,
+    reached_q3: usize,
+}
+
+fn progression_table(title: &str, is_team: bool, entries: BTreeMap<String, Progression>) -> Table {
+    let mut table = Table::new()
+        .title(title)
+        .column(if is_team { "Team" } else { "Driver" }, Align::Left)
+        .column("Entries", Align::Right)
+        .column("Q2", Align::Right)
+        .column("Q3", Align::Right)
+        .column("Q3 %", Align::Right);
+
+    let mut entries = entries.into_iter().collect::<Vec<_>>();
+    entries.sort_by_key(|(_, p)| Reverse((p.reached_q3, p.reached_q2)));
+    for (name, progression) in entries {
+        let q3_percent = progression.reached_q3 * 100 / progression.entries.max(1);
+        let color = if is_team { team_color(&name) } else { None };
+        table.add_row(vec![
+            Cell::colored(&name, color),
+            progression.entries.into(),
+            progression.reached_q2.into(),
+            progression.reached_q3.into(),
+            format!("{q3_percent}%").into(),
+        ]);
+    }
+    table
+}
+
+// Team counts are per car, so a team has two entries every round
+pub fn pp_season_progression(
+    qualifying: &[CompletedQualifying],
+    output: &mut String,
+) -> Result<()> {
+    let mut drivers: BTreeMap<String, Progression> = BTreeMap::new();
+    let mut teams: BTreeMap<String, Progression> = BTreeMap::new();
+    for quali in qualifying {
+        for driver in &quali.results {
+            let mut entries = vec![drivers.entry(driver.name.clone()).or_default()];
+            if let Some(team) = &driver.team {
+                entries.push(teams.entry(team.clone()).or_default());
+            }
+            let last_session = quali.last_session(driver);
+            for entry in entries {
+                entry.entries += 1;
+                if last_session >= QualiSession::Q2 {
+                    entry.reached_q2 += 1;
+                }
+                if last_session == QualiSession::Q3 {
+                    entry.reached_q3 += 1;
+                }
+            }
+        }
+    }
+
+    progression_table("DRIVERS REACHING Q2/Q3", false, drivers).render(output)?;
+    if !teams.is_empty() {
+        output.push('\n');
+        progression_table("TEAMS REACHING Q2/Q3", true, teams).render(output)?;
+    }
+    Ok(())
+}
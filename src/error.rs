@@ -5,14 +5,25 @@ pub enum Error {
     Fmt(std::fmt::Error),
     IO(std::io::Error),
     SerdeJson(serde_json::Error),
-    Ureq(ureq::Error),
+    // boxed as `ureq::Error` is much larger than all other variants
+    Ureq(Box<ureq::Error>),
     ParseInt(std::num::ParseIntError),
     Scraper,
     ParseDriverInfo(f32),
     ParseTeamInfo,
     ParseRaceResults,
+    ParseLapTime(String),
     // NoResults,
     InvalidArgs,
+    HttpRequest,
+    UnknownPointsSystem(String),
+    UnknownDriver(String),
+    UnknownTeam(String),
+    UnknownCircuit(String),
+    SameDriver(String),
+    // query & all names it matches
+    AmbiguousName(String, Vec<String>),
+    PredictionsLocked(String),
 }
 
 impl Display for Error {
@@ -32,11 +43,30 @@ impl Display for Error {
             }
             Self::ParseTeamInfo => write!(fmt, "Team table row parsing failed"),
             Self::ParseRaceResults => write!(fmt, "Race results table parsing failed"),
+            Self::ParseLapTime(lap_time) => write!(fmt, "Invalid lap time `{lap_time}`"),
             // Self::NoResults => write!(
             //     fmt,
             //     "There are no results for any GP, yet. Try `f1gp pull` to get new data"
             // ),
             Self::InvalidArgs => write!(fmt, "Invalid arguments provided"),
+            Self::HttpRequest => write!(fmt, "Malformed HTTP request"),
+            Self::UnknownPointsSystem(name) => write!(
+                fmt,
+                "Unknown points system `{name}`. Run `f1gp whatif` for available presets"
+            ),
+            Self::UnknownDriver(name) => write!(fmt, "No driver found matching `{name}`"),
+            Self::UnknownTeam(name) => write!(fmt, "No team found matching `{name}`"),
+            Self::UnknownCircuit(name) => write!(fmt, "No circuit found matching `{name}`"),
+            Self::SameDriver(name) => write!(fmt, "Both names match {name}, pick two drivers"),
+            Self::AmbiguousName(query, names) => write!(
+                fmt,
+                "`{query}` matches more than one name: {}",
+                names.join(", ")
+            ),
+            Self::PredictionsLocked(gp_name) => write!(
+                fmt,
+                "Predictions for {gp_name} are locked as qualifying has started"
+            ),
         }
     }
 }
@@ -58,7 +88,7 @@ impl From<serde_json::Error> for Error {
 }
 impl From<ureq::Error> for Error {
     fn from(err: ureq::Error) -> Self {
-        Self::Ureq(err)
+        Self::Ureq(Box::new(err))
     }
 }
 impl From<std::num::ParseIntError> for Error {
@@ -0,0 +1,251 @@
+use std::fmt::Write;
+
+use crate::error::{Error, Result};
+use crate::quali::CompletedQualifying;
+use crate::results::CompletedRace;
+use crate::schedule::{GrandPrix, QUALI, RACE, SPRINT};
+use crate::table::{Align, Table};
+use crate::utils::{flag_value, PositionInfo};
+
+// Sortable & understood by spreadsheets, times are in local timezone
+const EXPORT_DT_FMT: &str = "%Y-%m-%d %H:%M";
+
+#[derive(Clone, Copy)]
+pub enum ExportFormat {
+    Csv,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub fn from_arg(arg: &str) -> Result<Self> {
+        match arg.to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(Error::InvalidArgs),
+        }
+    }
+}
+
+// Format given with `--format`, `None` when terminal tables should be shown
+pub fn export_format(args: &[String]) -> Result<Option<ExportFormat>> {
+    flag_value(args, "--format")
+        .map(ExportFormat::from_arg)
+        .transpose()
+}
+
+// Plain values taken directly from the data structs, without the colors,
+// truncation & derived columns of terminal tables. Columns keep the alignment
+// of terminal tables for Markdown
+pub struct Records {
+    columns: Vec<(&'static str, Align)>,
+    rows: Vec<Vec<String>>,
+}
+
+// Quoted only when needed as per RFC 4180
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+impl Records {
+    pub fn render(&self, format: ExportFormat, output: &mut String) -> Result<()> {
+        match format {
+            ExportFormat::Csv => {
+                let write_line = |output: &mut String, fields: &[&str]| {
+                    let fields = fields.iter().map(|f| csv_field(f)).collect::<Vec<_>>();
+                    writeln!(output, "{}", fields.join(","))
+                };
+                let headers = self.columns.iter().map(|(h, _)| *h).collect::<Vec<_>>();
+                write_line(output, &headers)?;
+                for row in &self.rows {
+                    let row = row.iter().map(|f| f.as_str()).collect::<Vec<_>>();
+                    write_line(output, &row)?;
+                }
+                Ok(())
+            }
+            ExportFormat::Markdown => {
+                let mut table = Table::new();
+                for (header, align) in &self.columns {
+                    table = table.column(header, *align);
+                }
+                for row in &self.rows {
+                    table.add_row(row.iter().map(|f| f.into()).collect());
+                }
+                table.render_markdown(output)
+            }
+        }
+    }
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+pub fn standings_records(standings: &[PositionInfo], is_team: bool) -> Records {
+    let columns = if is_team {
+        vec![
+            ("Pos", Align::Right),
+            ("Team", Align::Left),
+            ("Points", Align::Right),
+        ]
+    } else {
+        vec![
+            ("Pos", Align::Right),
+            ("Driver", Align::Left),
+            ("Nationality", Align::Left),
+            ("Team", Align::Left),
+            ("Points", Align::Right),
+        ]
+    };
+    let rows = standings
+        .iter()
+        .map(|entry| {
+            let mut row = vec![entry.position.to_string(), entry.name.clone()];
+            if !is_team {
+                row.push(optional(entry.nationality.as_deref()));
+                row.push(optional(entry.team.as_deref()));
+            }
+            row.push(entry.points.to_string());
+            row
+        })
+        .collect();
+    Records { columns, rows }
+}
+
+pub fn race_records(race: &CompletedRace) -> Records {
+    let rows = race
+        .results
+        .iter()
+        .map(|driver| {
+            vec![
+                driver.pp_position(),
+                optional(driver.number),
+                driver.name.clone(),
+                optional(driver.team.as_deref()),
+                driver.points.to_string(),
+                optional(driver.reason.as_deref()),
+            ]
+        })
+        .collect();
+    Records {
+        columns: vec![
+            ("Pos", Align::Right),
+            ("No", Align::Right),
+            ("Driver", Align::Left),
+            ("Team", Align::Left),
+            ("Points", Align::Right),
+            ("Reason", Align::Left),
+        ],
+        rows,
+    }
+}
+
+pub fn quali_records(quali: &CompletedQualifying) -> Records {
+    let rows = quali
+        .results
+        .iter()
+        .map(|driver| {
+            vec![
+                driver.position.to_string(),
+                driver.name.clone(),
+                optional(driver.team.as_deref()),
+                optional(driver.q1),
+                optional(driver.q2),
+                optional(driver.q3),
+            ]
+        })
+        .collect();
+    Records {
+        columns: vec![
+            ("Pos", Align::Right),
+            ("Driver", Align::Left),
+            ("Team", Align::Left),
+            ("Q1", Align::Center),
+            ("Q2", Align::Center),
+            ("Q3", Align::Center),
+        ],
+        rows,
+    }
+}
+
+pub fn schedule_records(races: &[GrandPrix]) -> Records {
+    let rows = races
+        .iter()
+        .enumerate()
+        .map(|(idx, race)| {
+            let session = |name: &str| {
+                race.sessions()
+                    .into_iter()
+                    .find(|(session, _)| *session == name)
+                    .map(|(_, dt)| dt.format(EXPORT_DT_FMT).to_string())
+                    .unwrap_or_default()
+            };
+            vec![
+                (idx + 1).to_string(),
+                race.gp_name(),
+                race.location().to_owned(),
+                session(SPRINT),
+                session(QUALI),
+                session(RACE),
+            ]
+        })
+        .collect();
+    Records {
+        columns: vec![
+            ("Round", Align::Right),
+            ("Grand Prix", Align::Left),
+            ("Location", Align::Left),
+            ("Sprint", Align::Left),
+            ("Quali", Align::Left),
+            ("Race", Align::Left),
+        ],
+        rows,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_field_quoting() {
+        assert_eq!(csv_field("Max Verstappen"), "Max Verstappen");
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("Sao Paulo, Brazil"), "\"Sao Paulo, Brazil\"");
+        assert_eq!(csv_field("Power \"unit\""), "\"Power \"\"unit\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+    }
+
+    #[test]
+    fn markdown_keeps_column_alignment() {
+        let records = Records {
+            columns: vec![("Pos", Align::Right), ("Driver", Align::Left)],
+            rows: vec![vec!["1".to_owned(), "Lando Norris".to_owned()]],
+        };
+        let mut output = String::new();
+        records.render(ExportFormat::Markdown, &mut output).unwrap();
+        assert!(output.contains("| ---: | :--- |"));
+    }
+
+    #[test]
+    fn markdown_cells_stay_on_one_line() {
+        let records = Records {
+            columns: vec![("Driver", Align::Left), ("Reason", Align::Left)],
+            rows: vec![vec![
+                "Lando Norris".to_owned(),
+                "Collision\r\ndamage | lap 1\nretired\r".to_owned(),
+            ]],
+        };
+        let mut output = String::new();
+        records.render(ExportFormat::Markdown, &mut output).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[2],
+            "| Lando Norris | Collision damage \\| lap 1 retired  |"
+        );
+    }
+}
@@ -0,0 +1,407 @@
+use serde::Deserialize;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::error::Result;
+use crate::grid::{positions_gained, StartingGrid};
+use crate::h2h::beats;
+use crate::quali::CompletedQualifying;
+use crate::racecraft::start_position;
+use crate::results::CompletedRace;
+use crate::table::{team_color, Align, Cell, Color, Table};
+use crate::utils::{PositionInfo, RaceStatus};
+
+// Loaded from a JSON file, missing fields use the default rules, e.g.
+// {"finish": [10, 8, 6, 4, 2], "dnf": -5}
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct FantasyRules {
+    // points for P1, P2, .. in the race
+    pub finish: Vec<i64>,
+    // points for P1, P2, .. in qualifying
+    pub quali: Vec<i64>,
+    // per position gained or lost from grid slot to race finish
+    pub position_gained: i64,
+    pub position_lost: i64,
+    pub beat_teammate_race: i64,
+    pub beat_teammate_quali: i64,
+    // retired, not started or disqualified
+    pub dnf: i64,
+}
+
+impl Default for FantasyRules {
+    fn default() -> Self {
+        Self {
+            finish: vec![25, 18, 15, 12, 10, 8, 6, 4, 2, 1],
+            quali: vec![10, 9, 8, 7, 6, 5, 4, 3, 2, 1],
+            position_gained: 1,
+            position_lost: -1,
+            beat_teammate_race: 3,
+            beat_teammate_quali: 2,
+            dnf: -10,
+        }
+    }
+}
+
+impl FantasyRules {
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+}
+
+fn position_points(points: &[i64], position: usize) -> i64 {
+    match position {
+        0 => 0,
+        position => points.get(position - 1).copied().unwrap_or(0),
+    }
+}
+
+fn is_dnf(result: &PositionInfo) -> bool {
+    match result.status {
+        Some(status) => status != RaceStatus::Finished && status != RaceStatus::NotClassified,
+        // caches created by older versions only have position `0`
+        None => result.position == 0,
+    }
+}
+
+#[derive(Default)]
+struct FantasyScore {
+    finish: i64,
+    quali: i64,
+    positions: i64,
+    teammate: i64,
+    dnf: i64,
+}
+
+impl FantasyScore {
+    fn total(&self) -> i64 {
+        self.finish + self.quali + self.positions + self.teammate + self.dnf
+    }
+}
+
+struct DriverRound<'a> {
+    name: &'a str,
+    team: Option<&'a str>,
+    score: FantasyScore,
+}
+
+// Scores every driver classified or not in the race of the round
+fn score_round<'a>(
+    rules: &FantasyRules,
+    race: &'a CompletedRace,
+    quali: Option<&'a CompletedQualifying>,
+    grid: Option<&StartingGrid>,
+) -> Vec<DriverRound<'a>> {
+    let quali_results = quali.map(|q| q.results.as_slice()).unwrap_or_default();
+    let mut scores = Vec::new();
+    for result in &race.results {
+        let mut score = FantasyScore {
+            finish: position_points(&rules.finish, result.position),
+            ..Default::default()
+        };
+
+        let quali_result = quali_results.iter().find(|d| d.name == result.name);
+        if let Some(quali_result) = quali_result {
+            score.quali = position_points(&rules.quali, quali_result.position);
+        }
+        // grid penalties are only accounted for when the starting grid is cached
+        let start = start_position(&result.name, grid, quali);
+        score.positions = match start.and_then(|start| positions_gained(start, result.position)) {
+            Some(gained) if gained > 0 => gained * rules.position_gained,
+            Some(gained) => -gained * rules.position_lost,
+            None => 0,
+        };
+
+        // teammates are drivers of the same team in the same session
+        if let Some(team) = &result.team {
+            let race_wins = race
+                .results
+                .iter()
+                .filter(|d| d.name != result.name && d.team.as_ref() == Some(team))
+                .filter(|d| beats(result.position, d.position) == Some(true))
+                .count();
+            score.teammate += race_wins as i64 * rules.beat_teammate_race;
+        }
+        if let Some(quali_result) = quali_result {
+            if let Some(team) = &quali_result.team {
+                let quali_wins = quali_results
+                    .iter()
+                    .filter(|d| d.name != quali_result.name && d.team.as_ref() == Some(team))
+                    .filter(|d| beats(quali_result.position, d.position) == Some(true))
+                    .count();
+                score.teammate += quali_wins as i64 * rules.beat_teammate_quali;
+            }
+        }
+
+        if is_dnf(result) {
+            score.dnf = rules.dnf;
+        }
+        scores.push(DriverRound {
+            name: &result.name,
+            team: result.team.as_deref(),
+            score,
+        });
+    }
+    scores
+}
+
+fn pp_score(score: i64) -> Cell {
+    if score < 0 {
+        Cell::colored(score, Some(Color::RED))
+    } else {
+        score.into()
+    }
+}
+
+pub fn pp_round_fantasy_scores(
+    rules: &FantasyRules,
+    race: &CompletedRace,
+    quali: Option<&CompletedQualifying>,
+    grid: Option<&StartingGrid>,
+    output: &mut String,
+) -> Result<()> {
+    let mut scores = score_round(rules, race, quali, grid);
+    scores.sort_by_key(|d| Reverse(d.score.total()));
+
+    let mut table = Table::new()
+        .title(format!("{} - Fantasy Scores", race.gp_name))
+        .column("Driver", Align::Left)
+        .column("Team", Align::Left)
+        .column("Finish", Align::Right)
+        .column("Quali", Align::Right)
+        .column("+/-", Align::Right)
+        .column("Teammate", Align::Right)
+        .column("DNF", Align::Right)
+        .column("Total", Align::Right);
+    for driver in scores {
+        let team = driver.team.unwrap_or_default();
+        table.add_row(vec![
+            driver.name.into(),
+            Cell::colored(team, team_color(team)),
+            pp_score(driver.score.finish),
+            pp_score(driver.score.quali),
+            pp_score(driver.score.positions),
+            pp_score(driver.score.teammate),
+            pp_score(driver.score.dnf),
+            pp_score(driver.score.total()),
+        ]);
+    }
+    match (quali, grid) {
+        (None, None) => table
+            .add_footer("No qualifying results cached, quali & positions gained are not scored"),
+        (None, Some(_)) => table.add_footer("No qualifying results cached, quali is not scored"),
+        (Some(_), None) => {
+            table.add_footer("No starting grid cached, qualifying positions are used as start")
+        }
+        (Some(_), Some(_)) => {}
+    }
+    table.render(output)
+}
+
+fn season_table(
+    title: &str,
+    is_team: bool,
+    races: &[CompletedRace],
+    scores: BTreeMap<&str, BTreeMap<usize, i64>>,
+) -> Table {
+    let mut table = Table::new()
+        .title(title)
+        .column("Pos", Align::Right)
+        .column(if is_team { "Team" } else { "Driver" }, Align::Left);
+    for race in races {
+        table = table.column(format!("R{}", race.round), Align::Right);
+    }
+    table = table.column("Total", Align::Right);
+
+    let mut scores = scores.into_iter().collect::<Vec<_>>();
+    scores.sort_by_key(|(_, rounds)| Reverse(rounds.values().sum::<i64>()));
+    for (idx, (name, rounds)) in scores.into_iter().enumerate() {
+        let color = if is_team { team_color(name) } else { None };
+        let mut row: Vec<Cell> = vec![(idx + 1).into(), Cell::colored(name, color)];
+        for race in races {
+            row.push(match rounds.get(&race.round) {
+                Some(score) => pp_score(*score),
+                None => "".into(),
+            });
+        }
+        row.push(pp_score(rounds.values().sum()));
+        table.add_row(row);
+    }
+    table
+}
+
+pub fn pp_season_fantasy_scores(
+    rules: &FantasyRules,
+    races: &[CompletedRace],
+    qualifying: &[CompletedQualifying],
+    grids: &[StartingGrid],
+    output: &mut String,
+) -> Result<()> {
+    // name -> round -> score
+    let mut drivers: BTreeMap<&str, BTreeMap<usize, i64>> = BTreeMap::new();
+    let mut teams: BTreeMap<&str, BTreeMap<usize, i64>> = BTreeMap::new();
+    for race in races {
+        let quali = qualifying.iter().find(|q| q.round == race.round);
+        let grid = grids.iter().find(|g| g.round == race.round);
+        for driver in score_round(rules, race, quali, grid) {
+            let total = driver.score.total();
+            drivers
+                .entry(driver.name)
+                .or_default()
+                .insert(race.round, total);
+            if let Some(team) = driver.team {
+                *teams
+                    .entry(team)
+                    .or_default()
+                    .entry(race.round)
+                    .or_default() += total;
+            }
+        }
+    }
+
+    let mut drivers_table = season_table("FANTASY - DRIVERS", false, races, drivers);
+    if grids.is_empty() {
+        drivers_table
+            .add_footer("No starting grids cached, qualifying positions are used as start");
+    }
+    drivers_table.render(output)?;
+    output.push('\n');
+    season_table("FANTASY - TEAMS", true, races, teams).render(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn result(position: usize, name: &str, team: &str, status: &str) -> serde_json::Value {
+        json!({"position": position, "name": name, "points": 0, "team": team,
+            "status": status, "reason": null, "nationality": null, "number": null})
+    }
+
+    fn race() -> CompletedRace {
+        serde_json::from_value(json!({
+            "round": 1,
+            "gp_name": "Bahrain Grand Prix",
+            "results": [
+                result(1, "Max Verstappen", "Red Bull Racing", "Finished"),
+                result(2, "Lando Norris", "McLaren", "Finished"),
+                result(3, "Oscar Piastri", "McLaren", "Finished"),
+                result(0, "Sergio Perez", "Red Bull Racing", "Retired"),
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn quali() -> CompletedQualifying {
+        let driver = |position: usize, name: &str, team: &str| {
+            json!({"position": position, "name": name, "team": team,
+                "q1": null, "q2": null, "q3": null})
+        };
+        serde_json::from_value(json!({
+            "round": 1,
+            "gp_name": "Bahrain Grand Prix",
+            "results": [
+                driver(1, "Lando Norris", "McLaren"),
+                driver(2, "Sergio Perez", "Red Bull Racing"),
+                driver(3, "Oscar Piastri", "McLaren"),
+                driver(4, "Max Verstappen", "Red Bull Racing"),
+            ]
+        }))
+        .unwrap()
+    }
+
+    // Verstappen starts from the back after a grid penalty
+    fn grid() -> StartingGrid {
+        let slot = |position: usize, name: &str| json!({"position": position, "name": name, "team": null, "pit_lane": false});
+        serde_json::from_value(json!({
+            "round": 1,
+            "gp_name": "Bahrain Grand Prix",
+            "grid": [
+                slot(1, "Lando Norris"),
+                slot(2, "Sergio Perez"),
+                slot(3, "Oscar Piastri"),
+                slot(14, "Max Verstappen"),
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn score<'a>(scores: &'a [DriverRound], name: &str) -> &'a FantasyScore {
+        &scores.iter().find(|d| d.name == name).unwrap().score
+    }
+
+    #[test]
+    fn position_points_outside_table_are_zero() {
+        let points = [25, 18, 15];
+        assert_eq!(position_points(&points, 1), 25);
+        assert_eq!(position_points(&points, 3), 15);
+        assert_eq!(position_points(&points, 4), 0);
+        // not classified
+        assert_eq!(position_points(&points, 0), 0);
+    }
+
+    #[test]
+    fn dnf_excludes_finishers_and_not_classified() {
+        let race: CompletedRace = serde_json::from_value(json!({
+            "round": 1,
+            "gp_name": "Bahrain Grand Prix",
+            "results": [
+                result(1, "A", "X", "Finished"),
+                result(0, "B", "X", "NotClassified"),
+                result(0, "C", "X", "Retired"),
+                result(0, "D", "X", "DidNotStart"),
+                result(0, "E", "X", "Disqualified"),
+            ]
+        }))
+        .unwrap();
+        let dnfs = race.results.iter().map(is_dnf).collect::<Vec<_>>();
+        assert_eq!(dnfs, vec![false, false, true, true, true]);
+
+        // caches created by older versions don't have a status
+        let mut old = race;
+        old.results.iter_mut().for_each(|d| d.status = None);
+        assert!(!is_dnf(&old.results[0]) && is_dnf(&old.results[1]));
+    }
+
+    #[test]
+    fn round_score_breakdown() {
+        let rules = FantasyRules::default();
+        let (race, quali) = (race(), quali());
+        let scores = score_round(&rules, &race, Some(&quali), None);
+
+        let verstappen = score(&scores, "Max Verstappen");
+        assert_eq!(verstappen.finish, 25);
+        assert_eq!(verstappen.quali, 7);
+        // P4 in qualifying to P1
+        assert_eq!(verstappen.positions, 3);
+        // ahead of a retired teammate in the race, behind him in qualifying
+        assert_eq!(verstappen.teammate, 3);
+
+        let norris = score(&scores, "Lando Norris");
+        assert_eq!((norris.positions, norris.teammate), (-1, 3 + 2));
+        assert_eq!(norris.total(), 18 + 10 - 1 + 5);
+
+        let perez = score(&scores, "Sergio Perez");
+        assert_eq!((perez.finish, perez.positions, perez.dnf), (0, 0, -10));
+        assert_eq!(perez.teammate, 2);
+    }
+
+    #[test]
+    fn positions_gained_count_from_grid_slot() {
+        let rules = FantasyRules::default();
+        let (race, quali, grid) = (race(), quali(), grid());
+        let scores = score_round(&rules, &race, Some(&quali), Some(&grid));
+        // quali points still come from qualifying
+        assert_eq!(score(&scores, "Max Verstappen").quali, 7);
+        assert_eq!(score(&scores, "Max Verstappen").positions, 13);
+        assert_eq!(score(&scores, "Oscar Piastri").positions, 0);
+
+        // grid alone is enough to score positions gained
+        let scores = score_round(&rules, &race, None, Some(&grid));
+        assert_eq!(score(&scores, "Max Verstappen").positions, 13);
+        assert_eq!(score(&scores, "Max Verstappen").quali, 0);
+    }
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::laptime::LapTime;
+use crate::table::{team_color, Align, Cell, Table};
+use crate::utils::{cell_text, fetch_round_pages, DataFetcher, F1_BASE_URL, F1_TABLE_SELECTOR};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FastestLapInfo {
+    pub position: usize,
+    pub name: String,
+    pub team: Option<String>,
+    pub lap: usize,
+    pub time: LapTime,
+}
+
+impl FastestLapInfo {
+    pub fn pp_fastest_lap(&self) -> String {
+        format!(
+            "Fastest lap: {} {} (lap {})",
+            self.name, self.time, self.lap
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FastestLaps {
+    pub round: usize,
+    pub gp_name: String,
+    pub laps: Vec<FastestLapInfo>,
+}
+
+impl FastestLaps {
+    pub fn fastest(&self) -> Option<&FastestLapInfo> {
+        self.laps.iter().min_by_key(|l| l.time)
+    }
+}
+
+// Pos, No, Driver, Team, Lap, Time of day, Time, Avg speed
+fn parse_fastest_lap_row(cells: &[scraper::ElementRef]) -> Result<FastestLapInfo> {
+    if cells.len() < 7 {
+        return Err(Error::ParseRaceResults);
+    }
+    let full_name = cells[2].text().collect::<Vec<_>>();
+    let first = full_name.first().ok_or(Error::ParseRaceResults)?;
+    let second = full_name.get(2).ok_or(Error::ParseRaceResults)?;
+
+    Ok(FastestLapInfo {
+        position: cell_text(cells[0]).parse::<usize>().unwrap_or(0),
+        name: format!("{} {}", first, second),
+        team: Some(cell_text(cells[3])),
+        lap: cell_text(cells[4]).parse::<usize>()?,
+        time: cell_text(cells[6]).parse::<LapTime>()?,
+    })
+}
+
+// Rows which fail to parse, e.g. a driver without a lap time, are skipped
+// instead of failing the whole round
+fn parse_fastest_laps_page(body: String) -> Result<Vec<FastestLapInfo>> {
+    let document = scraper::Html::parse_document(&body);
+    let table_selector = scraper::Selector::parse(F1_TABLE_SELECTOR).map_err(|_| Error::Scraper)?;
+    let td_selector = scraper::Selector::parse("td").map_err(|_| Error::Scraper)?;
+
+    let mut laps = Vec::new();
+    for element in document.select(&table_selector) {
+        let cells = element.select(&td_selector).collect::<Vec<_>>();
+        match parse_fastest_lap_row(&cells) {
+            Ok(lap) => laps.push(lap),
+            Err(err) => eprintln!("Skipping fastest lap row: {err}"),
+        }
+    }
+    Ok(laps)
+}
+
+impl DataFetcher for FastestLaps {
+    type A = Vec<FastestLaps>;
+
+    fn cache_file_name(year: i32) -> String {
+        format!("{}_fastest_laps.json", year)
+    }
+
+    fn resource_url(year: i32) -> String {
+        println!("Fetching fastest laps for all completed Grand Prix");
+        format!("{}/en/results/{}/races", F1_BASE_URL, year)
+    }
+
+    fn process_data(raw_data: String, file_path: &Path) -> Result<Self::A> {
+        let mut all_laps: Vec<FastestLaps> = if file_path.exists() {
+            Self::read_from_cache(file_path).unwrap_or(Vec::new())
+        } else {
+            Vec::new()
+        };
+        let rounds_cached = all_laps.iter().map(|r| r.round).collect::<Vec<usize>>();
+        let fetched = fetch_round_pages(
+            raw_data,
+            rounds_cached,
+            "fastest-laps",
+            parse_fastest_laps_page,
+        )?;
+        all_laps.extend(fetched.into_iter().map(|page| FastestLaps {
+            round: page.round,
+            gp_name: page.gp_name,
+            laps: page.data,
+        }));
+        all_laps.sort_by_key(|l| l.round);
+        Ok(all_laps)
+    }
+}
+
+pub fn pp_season_fastest_laps(all_laps: &[FastestLaps], output: &mut String) -> Result<()> {
+    let mut table = Table::new()
+        .title("FASTEST LAPS")
+        .column("#", Align::Right)
+        .column("Grand Prix", Align::Left)
+        .column("Driver", Align::Left)
+        .column("Team", Align::Left)
+        .column("Time", Align::Center)
+        .column("Lap", Align::Right);
+
+    let mut tally: BTreeMap<&str, usize> = BTreeMap::new();
+    for round in all_laps {
+        let Some(fastest) = round.fastest() else {
+            continue;
+        };
+        *tally.entry(&fastest.name).or_default() += 1;
+        let team = fastest.team.as_deref().unwrap_or_default();
+        table.add_row(vec![
+            round.round.into(),
+            round.gp_name.as_str().into(),
+            fastest.name.as_str().into(),
+            Cell::colored(team, team_color(team)),
+            fastest.time.into(),
+            fastest.lap.into(),
+        ]);
+    }
+
+    let mut tally = tally.into_iter().collect::<Vec<_>>();
+    tally.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    let tally = tally
+        .iter()
+        .map(|(name, count)| format!("{name} {count}"))
+        .collect::<Vec<_>>();
+    if !tally.is_empty() {
+        table.add_footer(format!("Fastest laps: {}", tally.join(", ")));
+    }
+    table.render(output)
+}
@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::quali::CompletedQualifying;
+use crate::results::CompletedRace;
+use crate::table::{team_color, Align, Cell, Color, Table};
+use crate::utils::{cell_text, fetch_round_pages, DataFetcher, F1_BASE_URL, F1_TABLE_SELECTOR};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridPositionInfo {
+    // Slot on the grid, pit lane starters are placed after the last grid slot
+    pub position: usize,
+    pub name: String,
+    pub team: Option<String>,
+    pub pit_lane: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StartingGrid {
+    pub round: usize,
+    pub gp_name: String,
+    pub grid: Vec<GridPositionInfo>,
+}
+
+fn parse_starting_grid_page(body: String) -> Result<Vec<GridPositionInfo>> {
+    let document = scraper::Html::parse_document(&body);
+    let table_selector = scraper::Selector::parse(F1_TABLE_SELECTOR).map_err(|_| Error::Scraper)?;
+    let td_selector = scraper::Selector::parse("td").map_err(|_| Error::Scraper)?;
+
+    let mut grid = Vec::new();
+    for (idx, element) in document.select(&table_selector).enumerate() {
+        let mut iter = element.select(&td_selector);
+
+        // pit lane starters don't have a numeric grid slot
+        let position = cell_text(iter.next().ok_or(Error::ParseRaceResults)?);
+        let (position, pit_lane) = match position.parse::<usize>() {
+            Ok(position) => (position, false),
+            Err(_) => (idx + 1, true),
+        };
+
+        // skip car number
+        iter.next();
+
+        let full_name = iter
+            .next()
+            .ok_or(Error::ParseRaceResults)?
+            .text()
+            .collect::<Vec<_>>();
+        let first = full_name.first().ok_or(Error::ParseRaceResults)?;
+        let second = full_name.get(2).ok_or(Error::ParseRaceResults)?;
+        let name = format!("{} {}", first, second);
+
+        let team = iter.next().map(cell_text);
+
+        grid.push(GridPositionInfo {
+            position,
+            name,
+            team,
+            pit_lane,
+        });
+    }
+    Ok(grid)
+}
+
+impl DataFetcher for StartingGrid {
+    type A = Vec<StartingGrid>;
+
+    fn cache_file_name(year: i32) -> String {
+        format!("{}_starting_grid.json", year)
+    }
+
+    fn resource_url(year: i32) -> String {
+        println!("Fetching starting grids for all completed Grand Prix");
+        format!("{}/en/results/{}/races", F1_BASE_URL, year)
+    }
+
+    fn process_data(raw_data: String, file_path: &Path) -> Result<Self::A> {
+        let mut all_grids: Vec<StartingGrid> = if file_path.exists() {
+            Self::read_from_cache(file_path).unwrap_or(Vec::new())
+        } else {
+            Vec::new()
+        };
+        let rounds_cached = all_grids.iter().map(|r| r.round).collect::<Vec<usize>>();
+        let fetched = fetch_round_pages(
+            raw_data,
+            rounds_cached,
+            "starting-grid",
+            parse_starting_grid_page,
+        )?;
+        all_grids.extend(fetched.into_iter().map(|page| StartingGrid {
+            round: page.round,
+            gp_name: page.gp_name,
+            grid: page.data,
+        }));
+        all_grids.sort_by_key(|g| g.round);
+        Ok(all_grids)
+    }
+}
+
+// Difference between start and finish, unclassified drivers have no change
+pub fn positions_gained(start: usize, finish: usize) -> Option<i64> {
+    (finish != 0).then_some(start as i64 - finish as i64)
+}
+
+pub fn pp_positions_change(change: Option<i64>) -> Cell {
+    match change {
+        Some(change) if change > 0 => Cell::colored(format!("+{change}"), Some(Color::GREEN)),
+        Some(change) if change < 0 => Cell::colored(change, Some(Color::RED)),
+        Some(_) => "0".into(),
+        None => "-".into(),
+    }
+}
+
+pub fn pp_starting_grid(
+    grid: &StartingGrid,
+    quali: Option<&CompletedQualifying>,
+    race: Option<&CompletedRace>,
+    output: &mut String,
+) -> Result<()> {
+    let mut table = Table::new()
+        .title(format!("{} - Starting Grid", grid.gp_name))
+        .column("Grid", Align::Right)
+        .column("Driver", Align::Left)
+        .column("Team", Align::Left)
+        .column("Quali", Align::Right)
+        .column("Note", Align::Left)
+        .column("Finish", Align::Right)
+        .column("+/-", Align::Right);
+
+    for driver in &grid.grid {
+        let quali_pos = quali
+            .and_then(|q| q.results.iter().find(|d| d.name == driver.name))
+            .map(|d| d.position);
+        let note = match quali_pos {
+            _ if driver.pit_lane => Cell::colored("Pit lane start", Some(Color::RED)),
+            Some(quali_pos) if quali_pos != 0 && driver.position > quali_pos => Cell::colored(
+                format!("Penalty +{}", driver.position - quali_pos),
+                Some(Color::RED),
+            ),
+            _ => "".into(),
+        };
+        let result = race.and_then(|r| r.results.iter().find(|d| d.name == driver.name));
+        let finish = result.map(|d| d.position);
+
+        let team = driver.team.as_deref().unwrap_or_default();
+        table.add_row(vec![
+            if driver.pit_lane {
+                "PL".into()
+            } else {
+                driver.position.into()
+            },
+            driver.name.as_str().into(),
+            Cell::colored(team, team_color(team)),
+            quali_pos
+                .map(|p| p.to_string())
+                .unwrap_or("-".to_owned())
+                .into(),
+            note,
+            result
+                .map(|d| d.pp_position())
+                .unwrap_or("-".to_owned())
+                .into(),
+            pp_positions_change(finish.and_then(|f| positions_gained(driver.position, f))),
+        ]);
+    }
+    table.add_footer("+/- is positions gained from grid slot, not qualifying position");
+    table.render(output)
+}
@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+use crate::error::{Error, Result};
+use crate::quali::CompletedQualifying;
+use crate::results::CompletedRace;
+use crate::table::{Align, Cell, Color, Table};
+use crate::utils::{driver_code, find_driver_name, PositionInfo};
+
+// Position `0` is used for drivers who were not classified, so they lose
+// against anyone who was. Returns `None` when neither was classified
+pub fn beats(pos: usize, other_pos: usize) -> Option<bool> {
+    match (pos, other_pos) {
+        (0, 0) => None,
+        (0, _) => Some(false),
+        (_, 0) => Some(true),
+        (pos, other_pos) => Some(pos < other_pos),
+    }
+}
+
+// Unclassified runs show their status, e.g. `DNF` or `DSQ`
+fn pp_race_position(result: Option<&PositionInfo>) -> String {
+    match result {
+        Some(result) if result.position != 0 => format!("P{}", result.position),
+        Some(result) => result.pp_position(),
+        None => "-".to_owned(),
+    }
+}
+
+// Position `0` is a driver who did not set a time in qualifying
+fn pp_quali_position(position: Option<usize>) -> String {
+    match position {
+        Some(0) => "NC".to_owned(),
+        Some(position) => format!("P{position}"),
+        None => "-".to_owned(),
+    }
+}
+
+fn winner_color(won: bool) -> Option<Color> {
+    won.then_some(Color::GREEN)
+}
+
+#[derive(Default)]
+struct Tally {
+    race_wins: usize,
+    quali_wins: usize,
+    points: usize,
+}
+
+pub fn pp_head_to_head(
+    races: &[CompletedRace],
+    qualifying: &[CompletedQualifying],
+    first: &str,
+    second: &str,
+    output: &mut String,
+) -> Result<()> {
+    let all_names = races
+        .iter()
+        .flat_map(|r| r.results.iter().map(|p| p.name.as_str()))
+        .chain(
+            qualifying
+                .iter()
+                .flat_map(|q| q.results.iter().map(|p| p.name.as_str())),
+        );
+    let first = find_driver_name(all_names.clone(), first)?;
+    let second = find_driver_name(all_names, second)?;
+    if first == second {
+        return Err(Error::SameDriver(first));
+    }
+
+    let mut rounds: BTreeMap<usize, (Option<&CompletedRace>, Option<&CompletedQualifying>)> =
+        BTreeMap::new();
+    for race in races {
+        rounds.entry(race.round).or_default().0 = Some(race);
+    }
+    for quali in qualifying {
+        rounds.entry(quali.round).or_default().1 = Some(quali);
+    }
+
+    let (first_code, second_code) = (driver_code(&first), driver_code(&second));
+    let mut table = Table::new()
+        .title(format!("{first} vs {second}"))
+        .column("#", Align::Right)
+        .column("Grand Prix", Align::Left)
+        .column(format!("{first_code} Race"), Align::Center)
+        .column(format!("{second_code} Race"), Align::Center)
+        .column(format!("{first_code} Quali"), Align::Center)
+        .column(format!("{second_code} Quali"), Align::Center)
+        .column(format!("{first_code} Pts"), Align::Right)
+        .column(format!("{second_code} Pts"), Align::Right);
+
+    let (mut first_tally, mut second_tally) = (Tally::default(), Tally::default());
+    for (round, (race, quali)) in rounds {
+        let gp_name = race
+            .map(|r| r.gp_name.as_str())
+            .or(quali.map(|q| q.gp_name.as_str()))
+            .unwrap_or_default();
+
+        let race_result = |name: &str| race.and_then(|r| r.results.iter().find(|p| p.name == name));
+        let (first_race, second_race) = (race_result(&first), race_result(&second));
+        let quali_pos = |name: &str| {
+            quali.and_then(|q| {
+                q.results
+                    .iter()
+                    .find(|p| p.name == name)
+                    .map(|p| p.position)
+            })
+        };
+        let (first_quali, second_quali) = (quali_pos(&first), quali_pos(&second));
+
+        let race_winner = match (first_race, second_race) {
+            (Some(a), Some(b)) => beats(a.position, b.position),
+            _ => None,
+        };
+        let quali_winner = match (first_quali, second_quali) {
+            (Some(a), Some(b)) => beats(a, b),
+            _ => None,
+        };
+        match race_winner {
+            Some(true) => first_tally.race_wins += 1,
+            Some(false) => second_tally.race_wins += 1,
+            None => {}
+        }
+        match quali_winner {
+            Some(true) => first_tally.quali_wins += 1,
+            Some(false) => second_tally.quali_wins += 1,
+            None => {}
+        }
+        let first_points = first_race.map(|p| p.points).unwrap_or(0);
+        let second_points = second_race.map(|p| p.points).unwrap_or(0);
+        first_tally.points += first_points;
+        second_tally.points += second_points;
+
+        table.add_row(vec![
+            round.into(),
+            gp_name.into(),
+            Cell::colored(
+                pp_race_position(first_race),
+                winner_color(race_winner == Some(true)),
+            ),
+            Cell::colored(
+                pp_race_position(second_race),
+                winner_color(race_winner == Some(false)),
+            ),
+            Cell::colored(
+                pp_quali_position(first_quali),
+                winner_color(quali_winner == Some(true)),
+            ),
+            Cell::colored(
+                pp_quali_position(second_quali),
+                winner_color(quali_winner == Some(false)),
+            ),
+            first_points.into(),
+            second_points.into(),
+        ]);
+    }
+
+    table.add_footer(format!(
+        "Race H2H : {} {} - {} {}",
+        first, first_tally.race_wins, second_tally.race_wins, second
+    ));
+    table.add_footer(format!(
+        "Quali H2H: {} {} - {} {}",
+        first, first_tally.quali_wins, second_tally.quali_wins, second
+    ));
+    table.add_footer(format!(
+        "Points   : {} {} - {} {}",
+        first, first_tally.points, second_tally.points, second
+    ));
+    table.render(output)
+}
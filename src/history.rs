@@ -0,0 +1,127 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use crate::circuits::{find_circuit_in, Circuit};
+use crate::error::Result;
+use crate::quali::CompletedQualifying;
+use crate::results::CompletedRace;
+use crate::standings::driver_standings::DriverStandings;
+use crate::table::{team_color, Align, Cell, Table};
+use crate::utils::{flag_value, DataFetcher, PositionInfo};
+use crate::CURR_YEAR;
+
+// seasons loaded when no range is given
+const DEFAULT_SEASONS: i32 = 5;
+
+pub struct Season {
+    pub year: i32,
+    pub races: Vec<CompletedRace>,
+    pub qualifying: Vec<CompletedQualifying>,
+    // final standings for past seasons, empty if not available
+    pub driver_standings: Vec<PositionInfo>,
+}
+
+// Range given with `--from <year>` & `--to <year>`, defaults to the last few seasons
+pub fn season_range(args: &[String]) -> Result<(i32, i32)> {
+    let to = match flag_value(args, "--to") {
+        Some(year) => year.parse::<i32>()?,
+        None => *CURR_YEAR,
+    };
+    let from = match flag_value(args, "--from") {
+        Some(year) => year.parse::<i32>()?,
+        None => to - DEFAULT_SEASONS + 1,
+    };
+    Ok((from, to))
+}
+
+// Fetches & caches results of every season in `from..=to`. Seasons which
+// fail to load are reported & skipped, qualifying & standings are optional
+pub fn load_seasons(from: i32, to: i32) -> Vec<Season> {
+    let mut seasons = Vec::new();
+    for year in from..=to {
+        let races = match CompletedRace::get_season_data(year) {
+            Ok(races) => races,
+            Err(err) => {
+                eprintln!("Skipping {year} season: {err}");
+                continue;
+            }
+        };
+        let qualifying = CompletedQualifying::get_season_data(year).unwrap_or_default();
+        let driver_standings = DriverStandings::get_season_data(year).unwrap_or_default();
+        seasons.push(Season {
+            year,
+            races,
+            qualifying,
+            driver_standings,
+        });
+    }
+    seasons
+}
+
+fn driver_at(results: &[PositionInfo], position: usize) -> Option<&PositionInfo> {
+    results.iter().find(|d| d.position == position)
+}
+
+pub fn pp_circuit_history(
+    circuit: &Circuit,
+    seasons: &[Season],
+    output: &mut String,
+) -> Result<()> {
+    let mut table = Table::new()
+        .title(format!("{} - History", circuit.name))
+        .column("Year", Align::Left)
+        .column("Grand Prix", Align::Left)
+        .column("Pole", Align::Left)
+        .column("Winner", Align::Left)
+        .column("Team", Align::Left)
+        .column("2nd", Align::Left)
+        .column("3rd", Align::Left);
+
+    let mut wins: BTreeMap<&str, usize> = BTreeMap::new();
+    for season in seasons {
+        // a circuit may host more than one Grand Prix a season, e.g. Austria & Styria
+        let races = season.races.iter().filter(|race| {
+            find_circuit_in(&race.gp_name, season.year).is_some_and(|c| c.name == circuit.name)
+        });
+        for race in races {
+            let pole = season
+                .qualifying
+                .iter()
+                .find(|q| q.round == race.round)
+                .and_then(|q| q.results.iter().find(|d| d.position == 1))
+                .map(|d| d.name.as_str())
+                .unwrap_or("-");
+            let name_at = |position| {
+                driver_at(&race.results, position)
+                    .map(|d| d.name.as_str())
+                    .unwrap_or("-")
+            };
+            let winner = driver_at(&race.results, 1);
+            if let Some(winner) = winner {
+                *wins.entry(&winner.name).or_default() += 1;
+            }
+            let team = winner.and_then(|w| w.team.as_deref()).unwrap_or_default();
+            table.add_row(vec![
+                season.year.into(),
+                race.gp_name.as_str().into(),
+                pole.into(),
+                name_at(1).into(),
+                Cell::colored(team, team_color(team)),
+                name_at(2).into(),
+                name_at(3).into(),
+            ]);
+        }
+    }
+
+    let mut wins = wins.into_iter().collect::<Vec<_>>();
+    wins.sort_by_key(|(_, count)| Reverse(*count));
+    if let Some((_, most)) = wins.first() {
+        let leaders = wins
+            .iter()
+            .filter(|(_, count)| count == most)
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        table.add_footer(format!("Most wins: {} ({})", leaders.join(", "), most));
+    }
+    table.render(output)
+}
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::Error;
+
+// Lap time with millisecond precision as published in timing sheets.
+// Cached as the same `m:ss.sss` string shown on results pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LapTime {
+    millis: u64,
+}
+
+impl LapTime {
+    pub fn from_millis(millis: u64) -> Self {
+        Self { millis }
+    }
+
+    pub fn millis(&self) -> u64 {
+        self.millis
+    }
+
+    // Positive when `self` is slower than `other`
+    pub fn gap_to(&self, other: &LapTime) -> i64 {
+        self.millis as i64 - other.millis as i64
+    }
+}
+
+impl FromStr for LapTime {
+    type Err = Error;
+
+    // Parses `m:ss.sss`, `ss.sss` is also accepted
+    fn from_str(lap_time: &str) -> Result<Self, Self::Err> {
+        let lap_time = lap_time.trim();
+        let (minutes, seconds) = match lap_time.split_once(':') {
+            Some((minutes, seconds)) => (minutes.parse::<u64>()?, seconds),
+            None => (0, lap_time),
+        };
+        let (seconds, fraction) = seconds.split_once('.').unwrap_or((seconds, "0"));
+        if fraction.is_empty()
+            || fraction.len() > 3
+            || !fraction.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(Error::ParseLapTime(lap_time.to_owned()));
+        }
+        let seconds = seconds.parse::<u64>()?;
+        // `.5` is 500 milliseconds, padding fraction to three digits
+        let millis = format!("{fraction:0<3}").parse::<u64>()?;
+        Ok(Self::from_millis(
+            minutes * 60_000 + seconds * 1000 + millis,
+        ))
+    }
+}
+
+impl Display for LapTime {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let minutes = self.millis / 60_000;
+        let seconds = (self.millis % 60_000) / 1000;
+        let millis = self.millis % 1000;
+        if minutes > 0 {
+            write!(fmt, "{minutes}:{seconds:02}.{millis:03}")
+        } else {
+            write!(fmt, "{seconds}.{millis:03}")
+        }
+    }
+}
+
+impl Serialize for LapTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for LapTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let lap_time = String::deserialize(deserializer)?;
+        lap_time.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// Older caches stored raw cell text which can be `DNF`, `DNS` etc.
+// Anything not parsable as a lap time is treated as no time set
+pub fn deserialize_lenient<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<LapTime>, D::Error> {
+    let lap_time = Option::<String>::deserialize(deserializer)?;
+    Ok(lap_time.and_then(|t| t.parse().ok()))
+}
+
+// Gap in `+s.sss` format, e.g. `+0.123s`
+pub fn pp_gap(gap_millis: i64) -> String {
+    let sign = if gap_millis < 0 { "-" } else { "+" };
+    format!(
+        "{}{}.{:03}s",
+        sign,
+        gap_millis.abs() / 1000,
+        gap_millis.abs() % 1000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Entry {
+        #[serde(default, deserialize_with = "deserialize_lenient")]
+        time: Option<LapTime>,
+    }
+
+    #[test]
+    fn lap_time_from_str() {
+        assert_eq!("1:29.708".parse::<LapTime>().unwrap().millis(), 89_708);
+        assert_eq!("59.5".parse::<LapTime>().unwrap().millis(), 59_500);
+        assert_eq!(" 1:05.04 ".parse::<LapTime>().unwrap().millis(), 65_040);
+        assert!("1:29.".parse::<LapTime>().is_err());
+        assert!("1:29.7081".parse::<LapTime>().is_err());
+        assert!("DNF".parse::<LapTime>().is_err());
+    }
+
+    #[test]
+    fn lap_time_display_round_trips() {
+        for lap_time in ["1:29.708", "1:05.040", "59.500"] {
+            assert_eq!(lap_time.parse::<LapTime>().unwrap().to_string(), lap_time);
+        }
+    }
+
+    #[test]
+    fn lenient_deserialize_drops_non_times() {
+        let parse = |json: &str| serde_json::from_str::<Entry>(json).unwrap().time;
+        assert_eq!(
+            parse(r#"{"time":"1:29.708"}"#),
+            Some(LapTime::from_millis(89_708))
+        );
+        assert_eq!(parse(r#"{"time":"DNS"}"#), None);
+        assert_eq!(parse(r#"{"time":null}"#), None);
+        assert_eq!(parse("{}"), None);
+    }
+
+    #[test]
+    fn gap_formatting() {
+        let pole = LapTime::from_millis(89_708);
+        assert_eq!(
+            pp_gap(LapTime::from_millis(90_003).gap_to(&pole)),
+            "+0.295s"
+        );
+        assert_eq!(
+            pp_gap(LapTime::from_millis(88_600).gap_to(&pole)),
+            "-1.108s"
+        );
+        assert_eq!(pp_gap(0), "+0.000s");
+    }
+}
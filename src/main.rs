@@ -1,22 +1,66 @@
+mod career;
+mod circuits;
+mod eliminations;
 mod error;
+mod export;
+mod fantasy;
+mod fastest_laps;
+mod grid;
+mod h2h;
+mod history;
+mod laptime;
+mod notify;
+mod pitstops;
+mod points;
+mod predictions;
+mod profile;
 mod quali;
+mod racecraft;
+mod recap;
+mod reliability;
 mod results;
 mod schedule;
+mod server;
+mod simulate;
+mod sprint;
 mod standings;
+mod table;
+mod teammates;
 mod utils;
 
 use chrono::{Datelike, Local};
-use std::cmp::max;
 use std::fs::{read_dir, remove_file};
 use std::sync::LazyLock;
 
+use career::pp_driver_career;
+use circuits::{find_circuit, pp_circuit, search_circuit};
+use eliminations::{pp_round_eliminations, pp_season_cutoffs, pp_season_progression};
 use error::{Error, Result};
+use export::{export_format, quali_records, race_records, schedule_records, standings_records};
+use fantasy::{pp_round_fantasy_scores, pp_season_fantasy_scores, FantasyRules};
+use fastest_laps::{pp_season_fastest_laps, FastestLaps};
+use grid::{pp_starting_grid, StartingGrid};
+use h2h::pp_head_to_head;
+use history::{load_seasons, pp_circuit_history, season_range};
+use notify::run_notify_daemon;
+use pitstops::PitStops;
+use points::{pp_points_presets, pp_whatif_standings, PointsSystem};
+use predictions::{load_predictions, pp_league, pp_round_predictions, record_prediction};
+use profile::{pp_driver_profile, pp_team_profile};
 use quali::CompletedQualifying;
+use racecraft::{pp_driver_positions_gained, pp_positions_gained};
+use recap::{pp_recap, RecapFormat, Season};
+use reliability::pp_reliability;
 use results::CompletedRace;
-use schedule::Schedule;
+use schedule::{pp_race_list, watch_next_race, Schedule};
+use server::run_server;
+use simulate::run_simulation;
+use sprint::CompletedSprint;
 use standings::driver_standings::DriverStandings;
 use standings::team_standings::TeamStandings;
-use utils::{DataFetcher, TMP_DIR_NAME};
+use standings::{pp_standings, pp_standings_diff, StandingsSort};
+use teammates::pp_teammate_battles;
+use utils::{flag_value, has_flag, round_arg, DataFetcher, TMP_DIR_NAME};
 
 static CURR_YEAR: LazyLock<i32> = LazyLock::new(|| Local::now().year());
 
@@ -38,23 +82,21 @@ fn run() -> Result<()> {
         match arg.as_ref() {
             "list" => {
                 let mut output = String::new();
-                let info = "[x] Completed || [-> This weekend || [ ] Pending";
-
-                let mut bottom_border_len: usize = info.len();
-                for (idx, race) in Schedule::get_data()?.iter().enumerate() {
-                    let race_title = race.pp_race_title(curr_dt, idx + 1);
-                    bottom_border_len = max(bottom_border_len, race_title.len());
-                    output.push_str(&race_title);
-                    output.push('\n');
+                let rest = args.collect::<Vec<_>>();
+                if let Some(format) = export_format(&rest)? {
+                    schedule_records(&Schedule::get_data()?).render(format, &mut output)?;
+                    print!("{output}");
+                    return Ok(());
                 }
-                output.push_str(&"-".repeat(bottom_border_len));
-                output.push('\n');
-                output.push_str(info);
-                output.push('\n');
+                pp_race_list(&Schedule::get_data()?, &mut output)?;
                 println!("{output}");
             }
             "next" => {
-                let mut num_to_show = args.next().unwrap_or("1".to_owned()).parse::<u8>()?;
+                let next_arg = args.next();
+                if next_arg.as_deref() == Some("--watch") {
+                    return watch_next_race(&Schedule::get_data()?);
+                }
+                let mut num_to_show = next_arg.unwrap_or("1".to_owned()).parse::<u8>()?;
                 let mut output = String::new();
 
                 for race in Schedule::get_data()? {
@@ -97,29 +139,80 @@ fn run() -> Result<()> {
                 }
             }
             "drivers" => {
-                println!("DRIVER STANDINGS:");
-                println!("-----------------");
-                for driver in DriverStandings::get_data()? {
-                    println!("{:<20} {}", driver.name, driver.points)
+                let mut output = String::new();
+                let rest = args.collect::<Vec<_>>();
+                if let Some(format) = export_format(&rest)? {
+                    standings_records(&DriverStandings::get_data()?, false)
+                        .render(format, &mut output)?;
+                    print!("{output}");
+                    return Ok(());
                 }
+                if has_flag(&rest, "--diff") {
+                    let completed_races = CompletedRace::get_data()?;
+                    let Some(last_race) = completed_races.last() else {
+                        eprintln!("No Grand Prix races completed yet");
+                        return Ok(());
+                    };
+                    pp_standings_diff(
+                        &DriverStandings::get_data()?,
+                        &completed_races,
+                        &CompletedSprint::get_data()?,
+                        last_race,
+                        &mut output,
+                    )?;
+                    println!("{output}");
+                    return Ok(());
+                }
+                let sort = match flag_value(&rest, "--sort") {
+                    Some(sort) => StandingsSort::from_arg(sort)?,
+                    None => StandingsSort::Position,
+                };
+                // wins, podiums & poles are only read from cache, `f1gp pull` fetches them
+                pp_standings(
+                    "DRIVER STANDINGS",
+                    &DriverStandings::get_data()?,
+                    false,
+                    &CompletedRace::get_cached_data().unwrap_or_default(),
+                    &CompletedQualifying::get_cached_data().unwrap_or_default(),
+                    sort,
+                    &mut output,
+                )?;
+                println!("{output}");
             }
             "teams" => {
-                println!("TEAM STANDINGS:");
-                println!("---------------");
-                for team in TeamStandings::get_data()? {
-                    println!("{:<30} {}", team.name, team.points)
+                let mut output = String::new();
+                let rest = args.collect::<Vec<_>>();
+                if let Some(format) = export_format(&rest)? {
+                    standings_records(&TeamStandings::get_data()?, true)
+                        .render(format, &mut output)?;
+                    print!("{output}");
+                    return Ok(());
                 }
+                let sort = match flag_value(&rest, "--sort") {
+                    Some(sort) => StandingsSort::from_arg(sort)?,
+                    None => StandingsSort::Position,
+                };
+                pp_standings(
+                    "TEAM STANDINGS",
+                    &TeamStandings::get_data()?,
+                    true,
+                    &CompletedRace::get_cached_data().unwrap_or_default(),
+                    &CompletedQualifying::get_cached_data().unwrap_or_default(),
+                    sort,
+                    &mut output,
+                )?;
+                println!("{output}");
             }
             "quali" => {
                 let mut output = String::new();
                 let completed_quali = CompletedQualifying::get_data()?;
-                let round: usize = if let Some(arg) = args.next() {
-                    arg.parse()?
-                } else {
-                    completed_quali.len()
+                let rest = args.collect::<Vec<_>>();
+                let round: usize = match round_arg(&rest) {
+                    Some(arg) => arg.parse()?,
+                    None => completed_quali.len(),
                 };
 
-                if round < 1 || round > 25 {
+                if !(1..=25).contains(&round) {
                     eprintln!("Invalid round value given {}", round);
                     return Ok(());
                 }
@@ -129,20 +222,317 @@ fn run() -> Result<()> {
                 }
 
                 if let Some(race_result) = completed_quali.get(round - 1) {
+                    if let Some(format) = export_format(&rest)? {
+                        quali_records(race_result).render(format, &mut output)?;
+                        print!("{output}");
+                        return Ok(());
+                    }
                     race_result.pp_completed_quali_results(&mut output)?;
                     println!("{output}");
                 };
             }
-            "result" => {
+            "gains" => {
                 let mut output = String::new();
+                let (races, qualifying, grids) = (
+                    CompletedRace::get_data()?,
+                    CompletedQualifying::get_data()?,
+                    StartingGrid::get_data()?,
+                );
+                match args.next() {
+                    Some(query) => pp_driver_positions_gained(
+                        &races,
+                        &qualifying,
+                        &grids,
+                        &query,
+                        &mut output,
+                    )?,
+                    None => pp_positions_gained(&races, &qualifying, &grids, &mut output)?,
+                }
+                println!("{output}");
+            }
+            "pitstops" => {
+                let mut output = String::new();
+                let all_stops = PitStops::get_data()?;
+                let round: usize = if let Some(arg) = args.next() {
+                    arg.parse()?
+                } else {
+                    all_stops.last().map(|s| s.round).unwrap_or(0)
+                };
+                let Some(stops) = all_stops.iter().find(|s| s.round == round) else {
+                    eprintln!("Round {} does not have any pit stop data", round);
+                    return Ok(());
+                };
+                stops.pp_pit_stops(&mut output)?;
+                println!("{output}");
+            }
+            "fastest-laps" => {
+                let mut output = String::new();
+                pp_season_fastest_laps(&FastestLaps::get_data()?, &mut output)?;
+                println!("{output}");
+            }
+            "whatif" => {
+                let mut output = String::new();
+                let rest = args.collect::<Vec<_>>();
+                match flag_value(&rest, "--points") {
+                    Some(arg) => {
+                        let system = PointsSystem::from_arg(arg)?;
+                        // fastest laps are only needed for systems awarding a bonus point
+                        let fastest_laps = if system.fastest_lap > 0 {
+                            FastestLaps::get_data()?
+                        } else {
+                            Vec::new()
+                        };
+                        pp_whatif_standings(
+                            &CompletedRace::get_data()?,
+                            &fastest_laps,
+                            &system,
+                            &mut output,
+                        )?;
+                    }
+                    None => pp_points_presets(&mut output)?,
+                }
+                println!("{output}");
+            }
+            "simulate" => {
+                let mut output = String::new();
+                let rest = args.collect::<Vec<_>>();
+                run_simulation(
+                    &rest,
+                    &Schedule::get_data()?,
+                    &DriverStandings::get_data()?,
+                    &TeamStandings::get_data()?,
+                    &CompletedRace::get_data()?,
+                    &mut output,
+                )?;
+                println!("{output}");
+            }
+            "predict" => {
+                let rest = args.collect::<Vec<_>>();
+                record_prediction(&rest, &Schedule::get_data()?, &DriverStandings::get_data()?)?;
+            }
+            "predictions" => {
+                let mut output = String::new();
+                let predictions = load_predictions()?;
+                let round: usize = if let Some(arg) = args.next() {
+                    arg.parse()?
+                } else {
+                    predictions.iter().map(|p| p.round).max().unwrap_or(0)
+                };
+                if !predictions.iter().any(|p| p.round == round) {
+                    eprintln!("Round {} does not have any predictions", round);
+                    return Ok(());
+                }
+                pp_round_predictions(
+                    round,
+                    &predictions,
+                    &CompletedRace::get_data()?,
+                    &CompletedQualifying::get_data()?,
+                    &FastestLaps::get_data().unwrap_or_default(),
+                    &mut output,
+                )?;
+                println!("{output}");
+            }
+            "league" => {
+                let mut output = String::new();
+                pp_league(
+                    &load_predictions()?,
+                    &CompletedRace::get_data()?,
+                    &CompletedQualifying::get_data()?,
+                    &FastestLaps::get_data().unwrap_or_default(),
+                    &mut output,
+                )?;
+                println!("{output}");
+            }
+            "fantasy" => {
+                let mut output = String::new();
+                let rest = args.collect::<Vec<_>>();
+                let rules = match flag_value(&rest, "--rules") {
+                    Some(path) => FantasyRules::from_file(std::path::Path::new(path))?,
+                    None => FantasyRules::default(),
+                };
                 let completed_gp = CompletedRace::get_data()?;
+                let completed_quali = CompletedQualifying::get_data()?;
+                // grids are only read from cache, `f1gp pull` fetches them
+                let grids = StartingGrid::get_cached_data().unwrap_or_default();
+                match flag_value(&rest, "--round") {
+                    Some(round) => {
+                        let round: usize = round.parse()?;
+                        let Some(race) = completed_gp.iter().find(|r| r.round == round) else {
+                            eprintln!("Round {} does not have any results", round);
+                            return Ok(());
+                        };
+                        pp_round_fantasy_scores(
+                            &rules,
+                            race,
+                            completed_quali.iter().find(|q| q.round == round),
+                            grids.iter().find(|g| g.round == round),
+                            &mut output,
+                        )?;
+                    }
+                    None => pp_season_fantasy_scores(
+                        &rules,
+                        &completed_gp,
+                        &completed_quali,
+                        &grids,
+                        &mut output,
+                    )?,
+                }
+                println!("{output}");
+            }
+            "circuit" => {
+                let mut output = String::new();
+                let Some(arg) = args.next() else {
+                    eprintln!("Circuit round or name is required, e.g. f1gp circuit monza");
+                    return Ok(());
+                };
+                let schedule = Schedule::get_data()?;
+                let (circuit, gp) = match arg.parse::<usize>() {
+                    Ok(round) => {
+                        let Some(gp) = schedule.get(round.wrapping_sub(1)) else {
+                            eprintln!("Invalid round value given {}", round);
+                            return Ok(());
+                        };
+                        (find_circuit(&gp.gp_name(), gp.location()), Some(gp))
+                    }
+                    Err(_) => {
+                        let circuit = search_circuit(&arg)?;
+                        // schedule entry of the circuit if it is on this season's calendar
+                        let gp = schedule.iter().find(|gp| {
+                            find_circuit(&gp.gp_name(), gp.location())
+                                .is_some_and(|found| found.name == circuit.name)
+                        });
+                        (Some(circuit), gp)
+                    }
+                };
+                let Some(circuit) = circuit else {
+                    eprintln!("No circuit information found for {}", arg);
+                    return Ok(());
+                };
+                pp_circuit(circuit, gp, &mut output)?;
+                println!("{output}");
+            }
+            "history" => {
+                let mut output = String::new();
+                let rest = args.collect::<Vec<_>>();
+                let Some(query) = rest.first().filter(|arg| !arg.starts_with("--")) else {
+                    eprintln!("Circuit name is required, e.g. f1gp history monza");
+                    return Ok(());
+                };
+                let circuit = search_circuit(query)?;
+                let (from, to) = season_range(&rest)?;
+                let from = from.max(circuit.first_gp);
+                if !(from..=to).any(|year| circuit.hosted_gp(year)) {
+                    eprintln!("No Grand Prix held at {} between given years", circuit.name);
+                    return Ok(());
+                }
+                pp_circuit_history(circuit, &load_seasons(from, to), &mut output)?;
+                println!("{output}");
+            }
+            "driver" => {
+                let mut output = String::new();
+                let rest = args.collect::<Vec<_>>();
+                let Some(query) = rest.first().filter(|arg| !arg.starts_with("--")) else {
+                    eprintln!("Driver name is required, e.g. f1gp driver \"Lewis Hamilton\"");
+                    return Ok(());
+                };
+                // a season range switches from this season's profile to career stats,
+                // same as `career`. Without one this season's profile is shown
+                if has_flag(&rest, "--from") || has_flag(&rest, "--to") {
+                    let (from, to) = season_range(&rest)?;
+                    pp_driver_career(query, &load_seasons(from, to), &mut output)?;
+                } else {
+                    pp_driver_profile(
+                        query,
+                        &DriverStandings::get_data()?,
+                        &CompletedRace::get_data()?,
+                        &CompletedQualifying::get_data()?,
+                        &mut output,
+                    )?;
+                }
+                println!("{output}");
+            }
+            "career" => {
+                let mut output = String::new();
+                let rest = args.collect::<Vec<_>>();
+                let Some(query) = rest.first().filter(|arg| !arg.starts_with("--")) else {
+                    eprintln!("Driver name is required, e.g. f1gp career \"Lewis Hamilton\"");
+                    return Ok(());
+                };
+                let (from, to) = season_range(&rest)?;
+                pp_driver_career(query, &load_seasons(from, to), &mut output)?;
+                println!("{output}");
+            }
+            "team" => {
+                let mut output = String::new();
+                let Some(query) = args.next() else {
+                    eprintln!("Team name is required, e.g. f1gp team ferrari");
+                    return Ok(());
+                };
+                pp_team_profile(
+                    &query,
+                    &TeamStandings::get_data()?,
+                    &DriverStandings::get_data()?,
+                    &CompletedRace::get_data()?,
+                    &mut output,
+                )?;
+                println!("{output}");
+            }
+            "reliability" => {
+                let mut output = String::new();
+                pp_reliability(&CompletedRace::get_data()?, &mut output)?;
+                println!("{output}");
+            }
+            "cutoffs" => {
+                let mut output = String::new();
+                let completed_quali = CompletedQualifying::get_data()?;
+                match args.next() {
+                    None => pp_season_cutoffs(&completed_quali, &mut output)?,
+                    Some(arg) if arg == "--season" => {
+                        pp_season_progression(&completed_quali, &mut output)?
+                    }
+                    Some(arg) => {
+                        let round: usize = arg.parse()?;
+                        let Some(quali) = completed_quali.iter().find(|q| q.round == round) else {
+                            eprintln!("Round {} does not have any quali results", round);
+                            return Ok(());
+                        };
+                        pp_round_eliminations(quali, &mut output)?;
+                    }
+                }
+                println!("{output}");
+            }
+            "grid" => {
+                let mut output = String::new();
+                let grids = StartingGrid::get_data()?;
                 let round: usize = if let Some(arg) = args.next() {
                     arg.parse()?
                 } else {
-                    completed_gp.len()
+                    grids.last().map(|g| g.round).unwrap_or(0)
+                };
+                let Some(grid) = grids.iter().find(|g| g.round == round) else {
+                    eprintln!("Round {} does not have a starting grid", round);
+                    return Ok(());
+                };
+                let completed_quali = CompletedQualifying::get_data()?;
+                let completed_gp = CompletedRace::get_data()?;
+                pp_starting_grid(
+                    grid,
+                    completed_quali.iter().find(|q| q.round == round),
+                    completed_gp.iter().find(|r| r.round == round),
+                    &mut output,
+                )?;
+                println!("{output}");
+            }
+            "result" => {
+                let mut output = String::new();
+                let completed_gp = CompletedRace::get_data()?;
+                let rest = args.collect::<Vec<_>>();
+                let round: usize = match round_arg(&rest) {
+                    Some(arg) => arg.parse()?,
+                    None => completed_gp.len(),
                 };
 
-                if round < 1 || round > 25 {
+                if !(1..=25).contains(&round) {
                     eprintln!("Invalid round value given {}", round);
                     return Ok(());
                 }
@@ -151,16 +541,107 @@ fn run() -> Result<()> {
                     return Ok(());
                 }
                 if let Some(race_result) = completed_gp.get(round - 1) {
-                    race_result.pp_completed_race_results(&mut output)?;
+                    if let Some(format) = export_format(&rest)? {
+                        race_records(race_result).render(format, &mut output)?;
+                        print!("{output}");
+                        return Ok(());
+                    }
+                    // fastest laps are optional & only read from cache, `f1gp pull` fetches them
+                    let fastest_laps = FastestLaps::get_cached_data().unwrap_or_default();
+                    let fastest_lap = fastest_laps
+                        .iter()
+                        .find(|l| l.round == race_result.round)
+                        .and_then(|l| l.fastest());
+                    race_result.pp_completed_race_results(fastest_lap, &mut output)?;
                     println!("{output}");
                 };
             }
+            "recap" => {
+                let mut output = String::new();
+                let rest = args.collect::<Vec<_>>();
+                let completed_gp = CompletedRace::get_data()?;
+                let round: usize = match round_arg(&rest) {
+                    Some(arg) => arg.parse()?,
+                    None => completed_gp.len(),
+                };
+                let format = match flag_value(&rest, "--format") {
+                    Some(format) => RecapFormat::from_arg(format)?,
+                    None => RecapFormat::Markdown,
+                };
+                let Some(race) = completed_gp.iter().find(|r| r.round == round) else {
+                    eprintln!("Round {} does not have any results", round);
+                    return Ok(());
+                };
+                let schedule = Schedule::get_data()?;
+                let Some(gp) = schedule.get(round - 1) else {
+                    eprintln!("Invalid round value given {}", round);
+                    return Ok(());
+                };
+                // qualifying & standings are optional, recap is written even if fetching fails
+                let completed_quali = CompletedQualifying::get_data().unwrap_or_default();
+                let driver_standings = DriverStandings::get_data().unwrap_or_default();
+                // standings movement takes sprint points away, so it needs sprint results
+                let sprints = CompletedSprint::get_data().unwrap_or_else(|err| {
+                    if gp.is_sprint_weekend() {
+                        eprintln!("Sprint results not available, standings movement includes sprint points: {err}");
+                    }
+                    Vec::new()
+                });
+                pp_recap(
+                    gp,
+                    race,
+                    completed_quali.iter().find(|q| q.round == round),
+                    &Season {
+                        races: &completed_gp,
+                        sprints: &sprints,
+                        driver_standings: &driver_standings,
+                    },
+                    format,
+                    &mut output,
+                )?;
+                print!("{output}");
+            }
+            "notify" => {
+                let rest = args.collect::<Vec<_>>();
+                run_notify_daemon(&rest)?;
+            }
+            "serve" => {
+                let rest = args.collect::<Vec<_>>();
+                run_server(&rest)?;
+            }
+            "h2h" => {
+                let (Some(first), Some(second)) = (args.next(), args.next()) else {
+                    return Err(Error::InvalidArgs);
+                };
+                let mut output = String::new();
+                pp_head_to_head(
+                    &CompletedRace::get_data()?,
+                    &CompletedQualifying::get_data()?,
+                    &first,
+                    &second,
+                    &mut output,
+                )?;
+                println!("{output}");
+            }
+            "teammates" => {
+                let mut output = String::new();
+                pp_teammate_battles(
+                    &CompletedRace::get_data()?,
+                    &CompletedQualifying::get_data()?,
+                    &mut output,
+                )?;
+                println!("{output}");
+            }
             "pull" => {
                 Schedule::pull()?;
                 TeamStandings::pull()?;
                 DriverStandings::pull()?;
                 CompletedRace::pull()?;
+                CompletedSprint::pull()?;
                 CompletedQualifying::pull()?;
+                StartingGrid::pull()?;
+                FastestLaps::pull()?;
+                PitStops::pull()?;
             }
             "clean" => {
                 let dry_run = match args.next() {
@@ -190,18 +671,142 @@ fn run() -> Result<()> {
                     "{:<16}: Shows session schedule for next #num of Grand Prix Races",
                     "next <#>"
                 );
-                println!("{:<16}: Shows current driver standings", "drivers");
-                println!("{:<16}: Shows current team/constructor standings", "teams");
+                println!(
+                    "{:<16}: Redraws next Grand Prix schedule with a countdown every second",
+                    "next --watch"
+                );
+                println!(
+                    "{:<16}: Shows current driver standings with wins, podiums, poles & gaps. Options: --sort <pos|wins|podiums|poles|name>",
+                    "drivers"
+                );
+                println!(
+                    "{:<16}: Shows driver standings movement & points gained since the previous round",
+                    "drivers --diff"
+                );
+                println!(
+                    "{:<16}: Shows current team/constructor standings with wins, podiums, poles & gaps. Options: --sort <pos|wins|podiums|poles|name>",
+                    "teams"
+                );
                 println!("{:<16}: Shows last Grand Prix race result", "result");
                 println!(
                     "{:<16}: Shows results of the requested Grand Prix race(#round)",
                     "result <#>"
                 );
+                println!(
+                    "{:<16}: Writes data as CSV or Markdown table instead, for list, drivers, teams, result & quali",
+                    "--format <csv|markdown>"
+                );
+                println!(
+                    "{:<16}: Writes a weekend report of the last or requested Grand Prix(#round). Options: --format <markdown|text>",
+                    "recap [<#>]"
+                );
                 println!("{:<16}: Shows last Grand Prix qualifying result", "quali");
                 println!(
                     "{:<16}: Shows qualifying results of the requested Grand Prix(#round)",
                     "quali <#>"
                 );
+                println!(
+                    "{:<16}: Compares two drivers race by race, e.g. f1gp h2h \"Max Verstappen\" Norris",
+                    "h2h <d1> <d2>"
+                );
+                println!(
+                    "{:<16}: Shows race & qualifying battles between teammates of every team",
+                    "teammates"
+                );
+                println!(
+                    "{:<16}: Notifies before sessions start. Options: --before <mins,..> --sessions <quali,race,..> --bell --exec <cmd> --webhook <url>",
+                    "notify"
+                );
+                println!(
+                    "{:<16}: Serves cached data as JSON over HTTP. Options: --port <#> --refresh <mins>",
+                    "serve"
+                );
+                println!("{:<16}: Shows last Grand Prix starting grid", "grid");
+                println!(
+                    "{:<16}: Shows starting grid of the requested Grand Prix(#round) with penalties",
+                    "grid <#>"
+                );
+                println!(
+                    "{:<16}: Shows pit stop strategies & fastest stops of last Grand Prix",
+                    "pitstops"
+                );
+                println!(
+                    "{:<16}: Shows pit stop strategies & fastest stops of the requested Grand Prix(#round)",
+                    "pitstops <#>"
+                );
+                println!(
+                    "{:<16}: Shows fastest lap holder of every Grand Prix",
+                    "fastest-laps"
+                );
+                println!("{:<16}: Lists points system presets", "whatif");
+                println!(
+                    "{:<16}: Recalculates standings with a points preset or JSON file, e.g. f1gp whatif --points 2003",
+                    "whatif --points"
+                );
+                println!(
+                    "{:<16}: Projects title chances & final points. Options: --runs <#> --seed <#>",
+                    "simulate"
+                );
+                println!(
+                    "{:<16}: Records picks for next Grand Prix until qualifying starts. Options: --pole <driver> --podium <d1,d2,d3> --fastest-lap <driver> --round <#>",
+                    "predict <user>"
+                );
+                println!(
+                    "{:<16}: Shows everyone's picks & points for the requested Grand Prix(#round)",
+                    "predictions <#>"
+                );
+                println!(
+                    "{:<16}: Shows prediction league table of all users",
+                    "league"
+                );
+                println!(
+                    "{:<16}: Shows fantasy scores of drivers & teams per round. Options: --rules <file> --round <#>",
+                    "fantasy"
+                );
+                println!(
+                    "{:<16}: Shows circuit details & session times in track time, e.g. f1gp circuit monza",
+                    "circuit <#|name>"
+                );
+                println!(
+                    "{:<16}: Shows winners, pole sitters & podiums at a circuit. Options: --from <year> --to <year>",
+                    "history <name>"
+                );
+                println!(
+                    "{:<16}: Shows driver profile & race by race results of this season",
+                    "driver <name>"
+                );
+                println!(
+                    "{:<16}: Shows career stats of a driver per season, last 5 by default. Options: --from <year> --to <year>",
+                    "career <name>"
+                );
+                println!(
+                    "{:<16}: Same as career, e.g. f1gp driver hamilton --from 2014 --to 2024",
+                    "driver <name> --from <year> --to <year>"
+                );
+                println!(
+                    "{:<16}: Shows team profile, drivers & race by race results of this season",
+                    "team <name>"
+                );
+                println!(
+                    "{:<16}: Shows retirements per driver & team split by cause, NC, DNS & DSQ",
+                    "reliability"
+                );
+                println!(
+                    "{:<16}: Shows season positions gained, averages & best recoveries, or every race of a driver",
+                    "gains [<driver>]"
+                );
+                println!(
+                    "{:<16}: Shows Q1 & Q2 cut-off times of every qualifying",
+                    "cutoffs"
+                );
+                println!(
+                    "{:<16}: Shows drivers knocked out in the requested qualifying(#round) & margins",
+                    "cutoffs <#>"
+                );
+                println!(
+                    "{:<16}: Shows how often each driver & team reached Q2/Q3",
+                    "cutoffs --season"
+                );
                 println!(
                     "{:<16}: Pull latest data from sources. Required for updated standings",
                     "pull"
@@ -0,0 +1,301 @@
+use chrono::{DateTime, Local, TimeDelta};
+use std::io::Write;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+use crate::schedule::{Schedule, FP1, FP2, FP3, QUALI, RACE, SPRINT, SPR_QUALI, STR_FMT};
+use crate::utils::{flag_value, has_flag, DataFetcher};
+
+const DEFAULT_OFFSETS: &str = "30";
+const DEFAULT_SESSIONS: &str = "quali,sprint,race";
+// Upper bound for a single sleep, so that a suspended machine or a fresh
+// `f1gp pull` is noticed without waiting for the whole interval
+const MAX_SLEEP_SECS: i64 = 60;
+// Notifications found this late, e.g. after the machine resumed from suspend,
+// are dropped instead of firing long after they are useful
+const STALE_AFTER_SECS: i64 = 120;
+
+#[derive(Debug)]
+pub struct Notification {
+    gp_name: String,
+    session: &'static str,
+    start: DateTime<Local>,
+    minutes_before: i64,
+}
+
+impl Notification {
+    fn message(&self) -> String {
+        format!(
+            "{} of {} starts in {} minutes ({})",
+            self.session,
+            self.gp_name,
+            self.minutes_before,
+            self.start.format(STR_FMT)
+        )
+    }
+
+    fn trigger_dt(&self) -> DateTime<Local> {
+        self.start - TimeDelta::minutes(self.minutes_before)
+    }
+
+    fn is_stale(&self, now: DateTime<Local>) -> bool {
+        now - self.trigger_dt() > TimeDelta::seconds(STALE_AFTER_SECS)
+    }
+}
+
+pub trait NotificationSink {
+    fn notify(&self, notification: &Notification) -> Result<()>;
+}
+
+// Prints the message along with a terminal bell
+pub struct TerminalSink;
+
+impl NotificationSink for TerminalSink {
+    fn notify(&self, notification: &Notification) -> Result<()> {
+        let mut stdout = std::io::stdout();
+        writeln!(stdout, "\x07{}", notification.message())?;
+        stdout.flush()?;
+        Ok(())
+    }
+}
+
+// Runs a user provided shell command. Notification details are passed
+// as environment variables
+pub struct CommandSink {
+    command: String,
+}
+
+impl NotificationSink for CommandSink {
+    fn notify(&self, notification: &Notification) -> Result<()> {
+        let mut command = if cfg!(windows) {
+            let mut cmd = Command::new("cmd");
+            cmd.arg("/C");
+            cmd
+        } else {
+            let mut cmd = Command::new("sh");
+            cmd.arg("-c");
+            cmd
+        };
+        let status = command
+            .arg(&self.command)
+            .env("F1GP_GP", &notification.gp_name)
+            .env("F1GP_SESSION", notification.session)
+            .env("F1GP_START", notification.start.to_rfc3339())
+            .env(
+                "F1GP_MINUTES_BEFORE",
+                notification.minutes_before.to_string(),
+            )
+            .env("F1GP_MESSAGE", notification.message())
+            .status()?;
+        if !status.success() {
+            eprintln!("Notification command exited with {status}");
+        }
+        Ok(())
+    }
+}
+
+// POSTs notification as JSON to the given url
+pub struct WebhookSink {
+    url: String,
+}
+
+impl NotificationSink for WebhookSink {
+    fn notify(&self, notification: &Notification) -> Result<()> {
+        ureq::post(&self.url).send_json(serde_json::json!({
+            "grand_prix": notification.gp_name,
+            "session": notification.session,
+            "start": notification.start.to_rfc3339(),
+            "minutes_before": notification.minutes_before,
+            "message": notification.message(),
+        }))?;
+        Ok(())
+    }
+}
+
+fn parse_session_name(name: &str) -> Result<&'static str> {
+    let session = match name.trim().to_lowercase().as_str() {
+        "fp1" => FP1,
+        "fp2" => FP2,
+        "fp3" => FP3,
+        "quali" | "qualifying" => QUALI,
+        "sprint-quali" | "sprint-qualifying" => SPR_QUALI,
+        "sprint" => SPRINT,
+        "race" => RACE,
+        _ => return Err(Error::InvalidArgs),
+    };
+    Ok(session)
+}
+
+// Minutes before session start, negative offsets are rejected as notifications
+// after a session started are of no use
+fn parse_offsets(offsets: &str) -> Result<Vec<i64>> {
+    let mut parsed = Vec::new();
+    for offset in offsets.split(',') {
+        let offset = offset.trim().parse::<i64>()?;
+        if offset < 0 {
+            return Err(Error::InvalidArgs);
+        }
+        parsed.push(offset);
+    }
+    Ok(parsed)
+}
+
+fn upcoming_notifications(
+    sessions: &[&'static str],
+    offsets: &[i64],
+    after: DateTime<Local>,
+) -> Result<Vec<Notification>> {
+    let mut notifications = Vec::new();
+    for race in Schedule::get_data()? {
+        for (session, start) in race.sessions() {
+            if !sessions.contains(&session) {
+                continue;
+            }
+            for minutes_before in offsets {
+                let notification = Notification {
+                    gp_name: race.gp_name(),
+                    session,
+                    start,
+                    minutes_before: *minutes_before,
+                };
+                if notification.trigger_dt() > after {
+                    notifications.push(notification);
+                }
+            }
+        }
+    }
+    notifications.sort_by_key(|n| n.trigger_dt());
+    Ok(notifications)
+}
+
+// Runs until interrupted or until there are no more sessions in the schedule.
+// Sessions which are already closer than the offset at startup are not notified
+pub fn run_notify_daemon(args: &[String]) -> Result<()> {
+    let offsets = parse_offsets(flag_value(args, "--before").unwrap_or(DEFAULT_OFFSETS))?;
+    let sessions = flag_value(args, "--sessions")
+        .unwrap_or(DEFAULT_SESSIONS)
+        .split(',')
+        .map(parse_session_name)
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut sinks: Vec<Box<dyn NotificationSink>> = Vec::new();
+    if let Some(command) = flag_value(args, "--exec") {
+        sinks.push(Box::new(CommandSink {
+            command: command.to_owned(),
+        }));
+    }
+    if let Some(url) = flag_value(args, "--webhook") {
+        sinks.push(Box::new(WebhookSink {
+            url: url.to_owned(),
+        }));
+    }
+    if sinks.is_empty() || has_flag(args, "--bell") {
+        sinks.push(Box::new(TerminalSink));
+    }
+
+    let mut last_fired = Local::now();
+    loop {
+        // schedule is re-read every time, picking up any `f1gp pull` done meanwhile
+        let notifications = upcoming_notifications(&sessions, &offsets, last_fired)?;
+        let Some(notification) = notifications.first() else {
+            println!("No more sessions to notify");
+            return Ok(());
+        };
+
+        let now = Local::now();
+        let wait = notification.trigger_dt() - now;
+        if wait > TimeDelta::zero() {
+            let millis = wait.num_milliseconds().clamp(1, MAX_SLEEP_SECS * 1000);
+            std::thread::sleep(Duration::from_millis(millis as u64));
+            continue;
+        }
+
+        // more than one notification can be due at the same time
+        for notification in notifications.iter().take_while(|n| n.trigger_dt() <= now) {
+            if notification.is_stale(now) {
+                eprintln!("Skipping late notification: {}", notification.message());
+                continue;
+            }
+            for sink in &sinks {
+                if let Err(err) = sink.notify(notification) {
+                    eprintln!("Failed to send notification: {err}");
+                }
+            }
+        }
+        last_fired = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpListener;
+
+    fn notification(start: DateTime<Local>) -> Notification {
+        Notification {
+            gp_name: "Monaco Grand Prix".to_owned(),
+            session: RACE,
+            start,
+            minutes_before: 30,
+        }
+    }
+
+    // Accepts a single request and returns its body
+    fn read_request_body(listener: TcpListener) -> String {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+            .unwrap();
+        String::from_utf8(body).unwrap()
+    }
+
+    #[test]
+    fn webhook_posts_notification_json() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || read_request_body(listener));
+
+        let notification = notification(Local::now());
+        WebhookSink { url }.notify(&notification).unwrap();
+
+        let body: serde_json::Value = serde_json::from_str(&server.join().unwrap()).unwrap();
+        assert_eq!(body["grand_prix"], "Monaco Grand Prix");
+        assert_eq!(body["session"], RACE);
+        assert_eq!(body["minutes_before"], 30);
+        assert_eq!(body["message"], notification.message());
+    }
+
+    #[test]
+    fn negative_offsets_are_rejected() {
+        assert_eq!(parse_offsets("60, 30,0").unwrap(), vec![60, 30, 0]);
+        assert!(parse_offsets("30,-5").is_err());
+        assert!(parse_offsets("soon").is_err());
+    }
+
+    #[test]
+    fn late_notifications_are_stale() {
+        let now = Local::now();
+        // triggers 30 minutes before start, i.e. right now
+        assert!(!notification(now + TimeDelta::minutes(30)).is_stale(now));
+        // triggered an hour ago, e.g. machine was suspended
+        assert!(notification(now - TimeDelta::minutes(30)).is_stale(now));
+    }
+}
@@ -0,0 +1,189 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::laptime::LapTime;
+use crate::table::{team_color, Align, Cell, Table};
+use crate::utils::{cell_text, fetch_round_pages, DataFetcher, F1_BASE_URL, F1_TABLE_SELECTOR};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PitStopInfo {
+    pub stop: usize,
+    pub name: String,
+    pub team: Option<String>,
+    pub lap: usize,
+    pub time_of_day: String,
+    // Time spent in pit lane, stationary time is not published
+    pub duration: LapTime,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PitStops {
+    pub round: usize,
+    pub gp_name: String,
+    pub stops: Vec<PitStopInfo>,
+}
+
+// Stops, No, Driver, Team, Lap, Time of day, Time, Total
+fn parse_pit_stop_row(cells: &[scraper::ElementRef]) -> Result<PitStopInfo> {
+    if cells.len() < 7 {
+        return Err(Error::ParseRaceResults);
+    }
+    let full_name = cells[2].text().collect::<Vec<_>>();
+    let first = full_name.first().ok_or(Error::ParseRaceResults)?;
+    let second = full_name.get(2).ok_or(Error::ParseRaceResults)?;
+
+    Ok(PitStopInfo {
+        stop: cell_text(cells[0]).parse::<usize>()?,
+        name: format!("{} {}", first, second),
+        team: Some(cell_text(cells[3])),
+        lap: cell_text(cells[4]).parse::<usize>()?,
+        time_of_day: cell_text(cells[5]),
+        duration: cell_text(cells[6]).parse::<LapTime>()?,
+    })
+}
+
+// Rows which fail to parse, e.g. a stop without a pit lane time, are skipped
+// instead of failing the whole round & with it `f1gp pull`
+fn parse_pit_stop_summary_page(body: String) -> Result<Vec<PitStopInfo>> {
+    let document = scraper::Html::parse_document(&body);
+    let table_selector = scraper::Selector::parse(F1_TABLE_SELECTOR).map_err(|_| Error::Scraper)?;
+    let td_selector = scraper::Selector::parse("td").map_err(|_| Error::Scraper)?;
+
+    let mut stops = Vec::new();
+    for element in document.select(&table_selector) {
+        let cells = element.select(&td_selector).collect::<Vec<_>>();
+        match parse_pit_stop_row(&cells) {
+            Ok(stop) => stops.push(stop),
+            Err(err) => eprintln!("Skipping pit stop row: {err}"),
+        }
+    }
+    Ok(stops)
+}
+
+impl DataFetcher for PitStops {
+    type A = Vec<PitStops>;
+
+    fn cache_file_name(year: i32) -> String {
+        format!("{}_pit_stops.json", year)
+    }
+
+    fn resource_url(year: i32) -> String {
+        println!("Fetching pit stops for all completed Grand Prix");
+        format!("{}/en/results/{}/races", F1_BASE_URL, year)
+    }
+
+    fn process_data(raw_data: String, file_path: &Path) -> Result<Self::A> {
+        let mut all_stops: Vec<PitStops> = if file_path.exists() {
+            Self::read_from_cache(file_path).unwrap_or(Vec::new())
+        } else {
+            Vec::new()
+        };
+        let rounds_cached = all_stops.iter().map(|r| r.round).collect::<Vec<usize>>();
+        let fetched = fetch_round_pages(
+            raw_data,
+            rounds_cached,
+            "pit-stop-summary",
+            parse_pit_stop_summary_page,
+        )?;
+        all_stops.extend(fetched.into_iter().map(|page| PitStops {
+            round: page.round,
+            gp_name: page.gp_name,
+            stops: page.data,
+        }));
+        all_stops.sort_by_key(|s| s.round);
+        Ok(all_stops)
+    }
+}
+
+impl PitStops {
+    pub fn pp_pit_stops(&self, output: &mut String) -> Result<()> {
+        // stops in order of pit lane entry for every driver
+        let mut strategies: BTreeMap<&str, Vec<&PitStopInfo>> = BTreeMap::new();
+        for stop in &self.stops {
+            strategies.entry(&stop.name).or_default().push(stop);
+        }
+
+        let mut table = Table::new()
+            .title(format!("{} - Pit Stops", self.gp_name))
+            .column("Driver", Align::Left)
+            .column("Team", Align::Left)
+            .column("Stops", Align::Right)
+            .column("Laps", Align::Left)
+            .column("Pit Lane Time", Align::Right);
+        for (name, stops) in &strategies {
+            let team = stops[0].team.as_deref().unwrap_or_default();
+            let laps = stops
+                .iter()
+                .map(|s| s.lap.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let total = stops.iter().map(|s| s.duration.millis()).sum::<u64>();
+            table.add_row(vec![
+                (*name).into(),
+                Cell::colored(team, team_color(team)),
+                stops.len().into(),
+                laps.into(),
+                LapTime::from_millis(total).into(),
+            ]);
+        }
+        table.render(output)?;
+
+        let mut fastest_by_team: BTreeMap<&str, &PitStopInfo> = BTreeMap::new();
+        for stop in &self.stops {
+            let team = stop.team.as_deref().unwrap_or_default();
+            let fastest = fastest_by_team.entry(team).or_insert(stop);
+            if stop.duration < fastest.duration {
+                *fastest = stop;
+            }
+        }
+        let mut fastest_by_team = fastest_by_team.into_iter().collect::<Vec<_>>();
+        fastest_by_team.sort_by_key(|(_, stop)| stop.duration);
+
+        let mut table = Table::new()
+            .title("Fastest Stop by Team")
+            .column("Team", Align::Left)
+            .column("Driver", Align::Left)
+            .column("Lap", Align::Right)
+            .column("Time", Align::Right);
+        for (team, stop) in fastest_by_team {
+            table.add_row(vec![
+                Cell::colored(team, team_color(team)),
+                stop.name.as_str().into(),
+                stop.lap.into(),
+                stop.duration.into(),
+            ]);
+        }
+        table.add_footer("Times are pit lane durations, entry to exit");
+        output.push('\n');
+        table.render(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(stop: &str, lap: &str, time: &str) -> String {
+        format!(
+            "<tr><td>{stop}</td><td>1</td><td>Max<span> </span>Verstappen</td>\
+             <td>Red Bull Racing</td><td>{lap}</td><td>15:21:04</td><td>{time}</td>\
+             <td>{time}</td></tr>"
+        )
+    }
+
+    #[test]
+    fn unparsable_rows_are_skipped() {
+        let body = format!(
+            "<table class=\"f1-table\"><tbody>{}{}{}</tbody></table>",
+            row("1", "18", "22.456"),
+            row("2", "", "-"),
+            row("2", "41", "21.987"),
+        );
+        let stops = parse_pit_stop_summary_page(body).unwrap();
+        assert_eq!(stops.len(), 2);
+        assert_eq!(stops[0].name, "Max Verstappen");
+        assert_eq!((stops[1].stop, stops[1].lap), (2, 41));
+    }
+}
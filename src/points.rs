@@ -0,0 +1,270 @@
+use serde::Deserialize;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::fastest_laps::FastestLaps;
+use crate::grid::pp_positions_change;
+use crate::results::CompletedRace;
+use crate::table::{team_color, Align, Cell, Table};
+
+// name, description, points from P1 onwards, fastest lap bonus
+const PRESETS: [(&str, &str, &[usize], usize); 6] = [
+    (
+        "current",
+        "2025 onwards",
+        &[25, 18, 15, 12, 10, 8, 6, 4, 2, 1],
+        0,
+    ),
+    (
+        "2019",
+        "2019 - 2024, fastest lap point for top 10",
+        &[25, 18, 15, 12, 10, 8, 6, 4, 2, 1],
+        1,
+    ),
+    (
+        "2010",
+        "2010 - 2018",
+        &[25, 18, 15, 12, 10, 8, 6, 4, 2, 1],
+        0,
+    ),
+    ("2003", "2003 - 2009", &[10, 8, 6, 5, 4, 3, 2, 1], 0),
+    ("1991", "1991 - 2002", &[10, 6, 4, 3, 2, 1], 0),
+    ("1961", "1961 - 1990", &[9, 6, 4, 3, 2, 1], 0),
+];
+
+// Sprints have used the same points since 2022
+pub const SPRINT_POINTS: [usize; 8] = [8, 7, 6, 5, 4, 3, 2, 1];
+
+// Fastest lap bonus is only awarded to drivers finishing in this position or higher
+const FASTEST_LAP_TOP: usize = 10;
+
+#[derive(Debug, Deserialize)]
+pub struct PointsSystem {
+    pub name: String,
+    // points for P1, P2, .. positions not listed score nothing
+    pub points: Vec<usize>,
+    #[serde(default)]
+    pub fastest_lap: usize,
+}
+
+impl PointsSystem {
+    // `arg` is either a preset name or path to a JSON file, e.g.
+    // {"name": "Top 5", "points": [10, 7, 5, 3, 1], "fastest_lap": 1}
+    pub fn from_arg(arg: &str) -> Result<Self> {
+        if let Some((name, _, points, fastest_lap)) = PRESETS.iter().find(|p| p.0 == arg) {
+            return Ok(Self {
+                name: name.to_string(),
+                points: points.to_vec(),
+                fastest_lap: *fastest_lap,
+            });
+        }
+        let path = Path::new(arg);
+        if !path.is_file() {
+            return Err(Error::UnknownPointsSystem(arg.to_owned()));
+        }
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    pub fn points_for(&self, position: usize, fastest_lap: bool) -> usize {
+        if position == 0 {
+            return 0;
+        }
+        let points = self.points.get(position - 1).copied().unwrap_or(0);
+        if fastest_lap && position <= FASTEST_LAP_TOP {
+            points + self.fastest_lap
+        } else {
+            points
+        }
+    }
+
+    fn pp_points(&self) -> String {
+        let points = self
+            .points
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join("-");
+        if self.fastest_lap > 0 {
+            format!("{points}, +{} for fastest lap", self.fastest_lap)
+        } else {
+            points
+        }
+    }
+}
+
+pub fn pp_points_presets(output: &mut String) -> Result<()> {
+    let mut table = Table::new()
+        .title("POINTS SYSTEMS")
+        .column("Preset", Align::Left)
+        .column("Used in", Align::Left)
+        .column("Points", Align::Left);
+    for (name, description, points, fastest_lap) in PRESETS {
+        let system = PointsSystem {
+            name: name.to_owned(),
+            points: points.to_vec(),
+            fastest_lap,
+        };
+        table.add_row(vec![
+            name.into(),
+            description.into(),
+            system.pp_points().into(),
+        ]);
+    }
+    table.add_footer(
+        "A JSON file with `name`, `points` & optional `fastest_lap` can be used instead",
+    );
+    table.render(output)
+}
+
+#[derive(Default)]
+struct Tally {
+    points: usize,
+    // points actually scored in Grand Prix, without sprints
+    gp_points: usize,
+    // number of finishes in every position, used to break ties on points
+    finishes: Vec<usize>,
+}
+
+impl Tally {
+    fn add(&mut self, position: usize, points: usize, gp_points: usize) {
+        self.points += points;
+        self.gp_points += gp_points;
+        if position != 0 {
+            if self.finishes.len() < position {
+                self.finishes.resize(position, 0);
+            }
+            self.finishes[position - 1] += 1;
+        }
+    }
+}
+
+// Orders by points and then by count back, i.e. most wins, most 2nd places, ..
+fn rank<'a>(tallies: &BTreeMap<&'a str, Tally>, points: impl Fn(&Tally) -> usize) -> Vec<&'a str> {
+    let mut ranked = tallies.iter().collect::<Vec<_>>();
+    ranked.sort_by_key(|(_, t)| Reverse((points(t), t.finishes.clone())));
+    ranked.into_iter().map(|(name, _)| *name).collect()
+}
+
+fn whatif_table(title: &str, is_team: bool, tallies: &BTreeMap<&str, Tally>) -> Table {
+    let mut table = Table::new()
+        .title(title)
+        .column("Pos", Align::Right)
+        .column(if is_team { "Team" } else { "Driver" }, Align::Left)
+        .column("Points", Align::Right)
+        .column("GP Points", Align::Right)
+        .column("GP Pos", Align::Right)
+        .column("+/-", Align::Right);
+
+    let gp_ranking = rank(tallies, |t| t.gp_points);
+    for (idx, name) in rank(tallies, |t| t.points).into_iter().enumerate() {
+        let tally = &tallies[name];
+        let gp_pos = gp_ranking.iter().position(|n| *n == name).unwrap_or(idx);
+        let color = if is_team { team_color(name) } else { None };
+        table.add_row(vec![
+            (idx + 1).into(),
+            Cell::colored(name, color),
+            tally.points.into(),
+            tally.gp_points.into(),
+            (gp_pos + 1).into(),
+            pp_positions_change(Some(gp_pos as i64 - idx as i64)),
+        ]);
+    }
+    table
+}
+
+pub fn pp_whatif_standings(
+    races: &[CompletedRace],
+    fastest_laps: &[FastestLaps],
+    system: &PointsSystem,
+    output: &mut String,
+) -> Result<()> {
+    let mut drivers: BTreeMap<&str, Tally> = BTreeMap::new();
+    let mut teams: BTreeMap<&str, Tally> = BTreeMap::new();
+    let mut missing_fastest_laps = false;
+
+    for race in races {
+        let fastest = fastest_laps
+            .iter()
+            .find(|l| l.round == race.round)
+            .and_then(|l| l.fastest())
+            .map(|l| l.name.as_str());
+        missing_fastest_laps |= fastest.is_none();
+        for result in &race.results {
+            let points = system.points_for(result.position, fastest == Some(&result.name));
+            drivers
+                .entry(&result.name)
+                .or_default()
+                .add(result.position, points, result.points);
+            if let Some(team) = &result.team {
+                teams
+                    .entry(team)
+                    .or_default()
+                    .add(result.position, points, result.points);
+            }
+        }
+    }
+
+    let mut table = whatif_table(
+        &format!(
+            "DRIVER STANDINGS - {} ({})",
+            system.name,
+            system.pp_points()
+        ),
+        false,
+        &drivers,
+    );
+    table.add_footer(
+        "Only Grand Prix results are counted, GP Points are actual points without sprints",
+    );
+    if system.fastest_lap > 0 && missing_fastest_laps {
+        table.add_footer("Fastest laps are missing for some rounds, run `f1gp pull` to fetch them");
+    }
+    table.render(output)?;
+
+    output.push('\n');
+    whatif_table(&format!("TEAM STANDINGS - {}", system.name), true, &teams).render(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preset_points() {
+        let system = PointsSystem::from_arg("2003").unwrap();
+        assert_eq!(system.points_for(1, false), 10);
+        assert_eq!(system.points_for(8, false), 1);
+        assert_eq!(system.points_for(9, false), 0);
+        // not classified
+        assert_eq!(system.points_for(0, true), 0);
+
+        let system = PointsSystem::from_arg("2019").unwrap();
+        assert_eq!(system.points_for(1, true), 26);
+        assert_eq!(system.points_for(10, true), 2);
+        assert_eq!(system.points_for(11, true), 0);
+
+        assert!(matches!(
+            PointsSystem::from_arg("1950"),
+            Err(Error::UnknownPointsSystem(_))
+        ));
+    }
+
+    #[test]
+    fn ties_broken_by_count_back() {
+        let mut tallies: BTreeMap<&str, Tally> = BTreeMap::new();
+        // both on 18 points, Bravo has a win
+        tallies.entry("Alpha").or_default().add(2, 9, 9);
+        tallies.entry("Alpha").or_default().add(2, 9, 9);
+        tallies.entry("Bravo").or_default().add(1, 18, 18);
+        tallies.entry("Bravo").or_default().add(0, 0, 0);
+        // fewer points rank lower regardless of finishes
+        tallies.entry("Charlie").or_default().add(1, 10, 10);
+        assert_eq!(
+            rank(&tallies, |t| t.points),
+            vec!["Bravo", "Alpha", "Charlie"]
+        );
+    }
+}
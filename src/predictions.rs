@@ -0,0 +1,439 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::error::{Error, Result};
+use crate::fastest_laps::FastestLaps;
+use crate::quali::CompletedQualifying;
+use crate::results::CompletedRace;
+use crate::schedule::{GrandPrix, QUALI, STR_FMT};
+use crate::table::{Align, Cell, Color, Table};
+use crate::utils::{data_dir, driver_code, find_driver_name, flag_value, PositionInfo};
+use crate::CURR_YEAR;
+
+const POLE_POINTS: usize = 5;
+const PODIUM_EXACT_POINTS: usize = 5;
+const PODIUM_ANY_POINTS: usize = 2;
+const FASTEST_LAP_POINTS: usize = 3;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Prediction {
+    pub user: String,
+    pub round: usize,
+    pub pole: Option<String>,
+    // P1, P2 & P3 in order
+    pub podium: Vec<String>,
+    pub fastest_lap: Option<String>,
+    pub submitted: DateTime<Local>,
+}
+
+// Actual results a prediction is scored against
+struct RoundOutcome<'a> {
+    pole: Option<&'a str>,
+    podium: Vec<&'a str>,
+    fastest_lap: Option<&'a str>,
+}
+
+impl<'a> RoundOutcome<'a> {
+    // `None` until the race result is available
+    fn new(
+        round: usize,
+        races: &'a [CompletedRace],
+        qualifying: &'a [CompletedQualifying],
+        fastest_laps: &'a [FastestLaps],
+    ) -> Option<Self> {
+        let race = races.iter().find(|r| r.round == round)?;
+        let mut podium = race
+            .results
+            .iter()
+            .filter(|d| (1..=3).contains(&d.position))
+            .collect::<Vec<_>>();
+        podium.sort_by_key(|d| d.position);
+        Some(Self {
+            pole: qualifying
+                .iter()
+                .find(|q| q.round == round)
+                .and_then(|q| q.results.iter().find(|d| d.position == 1))
+                .map(|d| d.name.as_str()),
+            podium: podium.into_iter().map(|d| d.name.as_str()).collect(),
+            fastest_lap: fastest_laps
+                .iter()
+                .find(|l| l.round == round)
+                .and_then(|l| l.fastest())
+                .map(|l| l.name.as_str()),
+        })
+    }
+
+    fn podium_points(&self, position: usize, name: &str) -> usize {
+        if self.podium.get(position) == Some(&name) {
+            PODIUM_EXACT_POINTS
+        } else if self.podium.contains(&name) {
+            PODIUM_ANY_POINTS
+        } else {
+            0
+        }
+    }
+
+    fn score(&self, prediction: &Prediction) -> usize {
+        let pole = match (&prediction.pole, self.pole) {
+            (Some(pick), Some(pole)) if pick == pole => POLE_POINTS,
+            _ => 0,
+        };
+        let fastest_lap = match (&prediction.fastest_lap, self.fastest_lap) {
+            (Some(pick), Some(fastest)) if pick == fastest => FASTEST_LAP_POINTS,
+            _ => 0,
+        };
+        let podium = prediction
+            .podium
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| self.podium_points(idx, name))
+            .sum::<usize>();
+        pole + podium + fastest_lap
+    }
+}
+
+fn predictions_path() -> Result<PathBuf> {
+    Ok(data_dir()?.join(format!("{}_predictions.json", *CURR_YEAR)))
+}
+
+pub fn load_predictions() -> Result<Vec<Prediction>> {
+    let path = predictions_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let data = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&data)?)
+}
+
+fn save_predictions(predictions: &[Prediction]) -> Result<()> {
+    let data = serde_json::to_string_pretty(predictions)?;
+    std::fs::write(predictions_path()?, data)?;
+    Ok(())
+}
+
+// Picks lock when qualifying starts, as pole is one of the picks
+fn lock_dt(gp: &GrandPrix) -> DateTime<Local> {
+    gp.sessions()
+        .into_iter()
+        .find(|(name, _)| *name == QUALI)
+        .map(|(_, dt)| dt)
+        .unwrap_or(gp.gp_start_dt())
+}
+
+fn is_locked(gp: &GrandPrix, curr_dt: DateTime<Local>) -> bool {
+    lock_dt(gp) <= curr_dt
+}
+
+// Records picks of `user`, e.g.
+// f1gp predict alice --pole verstappen --podium verstappen,norris,leclerc --fastest-lap norris
+// Picks for the same round replace earlier ones, omitted picks are kept
+pub fn record_prediction(
+    args: &[String],
+    schedule: &[GrandPrix],
+    drivers: &[PositionInfo],
+) -> Result<()> {
+    let user = args
+        .first()
+        .filter(|arg| !arg.starts_with("--"))
+        .ok_or(Error::InvalidArgs)?;
+    let curr_dt = Local::now();
+    let round = match flag_value(args, "--round") {
+        Some(round) => round.parse::<usize>()?,
+        None => schedule
+            .iter()
+            .position(|gp| !is_locked(gp, curr_dt))
+            .map(|idx| idx + 1)
+            .ok_or(Error::InvalidArgs)?,
+    };
+    let gp = schedule
+        .get(round.wrapping_sub(1))
+        .ok_or(Error::InvalidArgs)?;
+    if is_locked(gp, curr_dt) {
+        return Err(Error::PredictionsLocked(gp.gp_name()));
+    }
+
+    let driver =
+        |query: &str| find_driver_name(drivers.iter().map(|d| d.name.as_str()), query.trim());
+    let pole = flag_value(args, "--pole").map(driver).transpose()?;
+    let fastest_lap = flag_value(args, "--fastest-lap").map(driver).transpose()?;
+    let podium = match flag_value(args, "--podium") {
+        Some(podium) => {
+            let podium = podium.split(',').map(driver).collect::<Result<Vec<_>>>()?;
+            let is_unique = podium
+                .iter()
+                .all(|d| podium.iter().filter(|o| *o == d).count() == 1);
+            if podium.len() != 3 || !is_unique {
+                return Err(Error::InvalidArgs);
+            }
+            podium
+        }
+        None => Vec::new(),
+    };
+    if pole.is_none() && fastest_lap.is_none() && podium.is_empty() {
+        return Err(Error::InvalidArgs);
+    }
+
+    let mut predictions = load_predictions()?;
+    let idx = match predictions
+        .iter()
+        .position(|p| p.user == *user && p.round == round)
+    {
+        Some(idx) => idx,
+        None => {
+            predictions.push(Prediction {
+                user: user.to_owned(),
+                round,
+                pole: None,
+                podium: Vec::new(),
+                fastest_lap: None,
+                submitted: curr_dt,
+            });
+            predictions.len() - 1
+        }
+    };
+    let prediction = &mut predictions[idx];
+    prediction.submitted = curr_dt;
+    if pole.is_some() {
+        prediction.pole = pole;
+    }
+    if fastest_lap.is_some() {
+        prediction.fastest_lap = fastest_lap;
+    }
+    if !podium.is_empty() {
+        prediction.podium = podium;
+    }
+    save_predictions(&predictions)?;
+
+    println!(
+        "Saved picks of {} for {}. Picks can be changed until {}",
+        user,
+        gp.gp_name(),
+        lock_dt(gp).format(STR_FMT)
+    );
+    Ok(())
+}
+
+fn pp_pick(pick: Option<&str>, correct: bool) -> Cell {
+    match pick {
+        Some(pick) => Cell::colored(driver_code(pick), correct.then_some(Color::GREEN)),
+        None => "-".into(),
+    }
+}
+
+pub fn pp_round_predictions(
+    round: usize,
+    predictions: &[Prediction],
+    races: &[CompletedRace],
+    qualifying: &[CompletedQualifying],
+    fastest_laps: &[FastestLaps],
+    output: &mut String,
+) -> Result<()> {
+    let outcome = RoundOutcome::new(round, races, qualifying, fastest_laps);
+    let mut table = Table::new()
+        .title(format!("PREDICTIONS - ROUND {round}"))
+        .column("User", Align::Left)
+        .column("Pole", Align::Center)
+        .column("P1", Align::Center)
+        .column("P2", Align::Center)
+        .column("P3", Align::Center)
+        .column("Fastest Lap", Align::Center)
+        .column("Points", Align::Right);
+
+    for prediction in predictions.iter().filter(|p| p.round == round) {
+        let mut row = vec![
+            prediction.user.as_str().into(),
+            pp_pick(
+                prediction.pole.as_deref(),
+                outcome.as_ref().and_then(|o| o.pole) == prediction.pole.as_deref(),
+            ),
+        ];
+        for idx in 0..3 {
+            let pick = prediction.podium.get(idx).map(|p| p.as_str());
+            let correct = match (&outcome, pick) {
+                (Some(outcome), Some(pick)) => outcome.podium_points(idx, pick) > 0,
+                _ => false,
+            };
+            row.push(pp_pick(pick, correct));
+        }
+        row.push(pp_pick(
+            prediction.fastest_lap.as_deref(),
+            outcome.as_ref().and_then(|o| o.fastest_lap) == prediction.fastest_lap.as_deref(),
+        ));
+        row.push(match &outcome {
+            Some(outcome) => outcome.score(prediction).into(),
+            None => "-".into(),
+        });
+        table.add_row(row);
+    }
+    if outcome.is_none() {
+        table.add_footer("Not scored yet, run `f1gp pull` after the race");
+    }
+    table.render(output)
+}
+
+pub fn pp_league(
+    predictions: &[Prediction],
+    races: &[CompletedRace],
+    qualifying: &[CompletedQualifying],
+    fastest_laps: &[FastestLaps],
+    output: &mut String,
+) -> Result<()> {
+    let mut rounds = predictions.iter().map(|p| p.round).collect::<Vec<_>>();
+    rounds.sort();
+    rounds.dedup();
+    let outcomes = rounds
+        .into_iter()
+        .filter_map(|round| {
+            Some((
+                round,
+                RoundOutcome::new(round, races, qualifying, fastest_laps)?,
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    // user -> round -> points
+    let mut scores: BTreeMap<&str, BTreeMap<usize, usize>> = BTreeMap::new();
+    for prediction in predictions {
+        let entry = scores.entry(&prediction.user).or_default();
+        if let Some((_, outcome)) = outcomes.iter().find(|(r, _)| *r == prediction.round) {
+            entry.insert(prediction.round, outcome.score(prediction));
+        }
+    }
+
+    let mut table = Table::new()
+        .title("PREDICTION LEAGUE")
+        .column("Pos", Align::Right)
+        .column("User", Align::Left);
+    for (round, _) in &outcomes {
+        table = table.column(format!("R{round}"), Align::Right);
+    }
+    table = table.column("Total", Align::Right);
+
+    let mut scores = scores.into_iter().collect::<Vec<_>>();
+    scores.sort_by_key(|(_, rounds)| Reverse(rounds.values().sum::<usize>()));
+    for (idx, (user, user_scores)) in scores.into_iter().enumerate() {
+        let mut row: Vec<Cell> = vec![(idx + 1).into(), user.into()];
+        for (round, _) in &outcomes {
+            row.push(match user_scores.get(round) {
+                Some(points) => (*points).into(),
+                None => "-".into(),
+            });
+        }
+        row.push(user_scores.values().sum::<usize>().into());
+        table.add_row(row);
+    }
+    table.add_footer(format!(
+        "Pole {POLE_POINTS}, podium exact {PODIUM_EXACT_POINTS} or on podium {PODIUM_ANY_POINTS}, fastest lap {FASTEST_LAP_POINTS}"
+    ));
+    table.render(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeDelta, TimeZone, Utc};
+    use serde_json::json;
+
+    const VER: &str = "Max Verstappen";
+    const NOR: &str = "Lando Norris";
+    const LEC: &str = "Charles Leclerc";
+    const PIA: &str = "Oscar Piastri";
+
+    fn outcome() -> RoundOutcome<'static> {
+        RoundOutcome {
+            pole: Some(NOR),
+            podium: vec![VER, NOR, LEC],
+            fastest_lap: Some(LEC),
+        }
+    }
+
+    fn prediction(pole: Option<&str>, podium: [&str; 3], fastest_lap: Option<&str>) -> Prediction {
+        Prediction {
+            user: "alice".to_owned(),
+            round: 1,
+            pole: pole.map(str::to_owned),
+            podium: podium.iter().map(|d| d.to_string()).collect(),
+            fastest_lap: fastest_lap.map(str::to_owned),
+            submitted: Local::now(),
+        }
+    }
+
+    #[test]
+    fn podium_points_exact_or_anywhere_on_podium() {
+        let outcome = outcome();
+        assert_eq!(outcome.podium_points(0, VER), PODIUM_EXACT_POINTS);
+        assert_eq!(outcome.podium_points(2, LEC), PODIUM_EXACT_POINTS);
+        assert_eq!(outcome.podium_points(0, NOR), PODIUM_ANY_POINTS);
+        assert_eq!(outcome.podium_points(1, PIA), 0);
+    }
+
+    #[test]
+    fn score_sums_all_picks() {
+        let outcome = outcome();
+        let perfect = prediction(Some(NOR), [VER, NOR, LEC], Some(LEC));
+        assert_eq!(
+            outcome.score(&perfect),
+            POLE_POINTS + 3 * PODIUM_EXACT_POINTS + FASTEST_LAP_POINTS
+        );
+        // podium in the wrong order, one driver off the podium
+        let shuffled = prediction(Some(VER), [NOR, VER, PIA], Some(NOR));
+        assert_eq!(outcome.score(&shuffled), 2 * PODIUM_ANY_POINTS);
+        // picks left out score nothing
+        let pole_only = prediction(Some(NOR), ["", "", ""], None);
+        assert_eq!(outcome.score(&pole_only), POLE_POINTS);
+    }
+
+    #[test]
+    fn outcome_without_pole_or_fastest_lap_data() {
+        let finisher = |position: usize, name: &str| {
+            json!({"position": position, "name": name, "points": 0, "team": null,
+                "status": null, "reason": null, "nationality": null, "number": null})
+        };
+        let results = vec![
+            finisher(3, LEC),
+            finisher(1, VER),
+            finisher(2, NOR),
+            finisher(4, PIA),
+        ];
+        let race: Vec<CompletedRace> = serde_json::from_value(json!([{
+            "round": 1,
+            "gp_name": "Bahrain Grand Prix",
+            "results": results
+        }]))
+        .unwrap();
+        assert!(RoundOutcome::new(2, &race, &[], &[]).is_none());
+        let outcome = RoundOutcome::new(1, &race, &[], &[]).unwrap();
+        assert_eq!(outcome.podium, vec![VER, NOR, LEC]);
+        let picks = prediction(Some(NOR), [VER, NOR, LEC], Some(LEC));
+        assert_eq!(outcome.score(&picks), 3 * PODIUM_EXACT_POINTS);
+    }
+
+    #[test]
+    fn picks_lock_at_qualifying_start() {
+        let gp: GrandPrix = serde_json::from_value(json!({
+            "name": "Miami",
+            "location": "Miami",
+            "sessions": {
+                "fp1": "2026-05-22T16:30:00Z",
+                "sprintQualifying": "2026-05-22T20:30:00Z",
+                "sprint": "2026-05-23T16:00:00Z",
+                "qualifying": "2026-05-23T20:00:00Z",
+                "gp": "2026-05-24T20:00:00Z"
+            }
+        }))
+        .unwrap();
+        let quali = Utc
+            .with_ymd_and_hms(2026, 5, 23, 20, 0, 0)
+            .unwrap()
+            .with_timezone(&Local);
+        assert_eq!(lock_dt(&gp), quali);
+        // sprint sessions don't lock picks
+        assert!(!is_locked(&gp, quali - TimeDelta::hours(4)));
+        assert!(!is_locked(&gp, quali - TimeDelta::seconds(1)));
+        assert!(is_locked(&gp, quali));
+        assert!(is_locked(&gp, gp.gp_start_dt()));
+    }
+}
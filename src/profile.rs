@@ -0,0 +1,191 @@
+use crate::error::Result;
+use crate::quali::CompletedQualifying;
+use crate::results::CompletedRace;
+use crate::table::{team_color, Align, Cell, Table};
+use crate::utils::{driver_code, find_driver_name, find_team_name, same_team, PositionInfo};
+
+fn info_table(title: &str, rows: Vec<(&str, Cell)>) -> Table {
+    let mut table = Table::new()
+        .title(title)
+        .column("", Align::Left)
+        .column("", Align::Left);
+    for (label, value) in rows {
+        table.add_row(vec![label.into(), value]);
+    }
+    table
+}
+
+fn pp_optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or("-".to_owned())
+}
+
+pub fn pp_driver_profile(
+    query: &str,
+    driver_standings: &[PositionInfo],
+    races: &[CompletedRace],
+    qualifying: &[CompletedQualifying],
+    output: &mut String,
+) -> Result<()> {
+    let names = driver_standings.iter().map(|d| d.name.as_str()).chain(
+        races
+            .iter()
+            .flat_map(|r| r.results.iter().map(|d| d.name.as_str())),
+    );
+    let name = find_driver_name(names, query)?;
+
+    let standing = driver_standings.iter().find(|d| d.name == name);
+    // latest race entry has the current car number & team
+    let latest = races
+        .iter()
+        .rev()
+        .find_map(|r| r.results.iter().find(|d| d.name == name));
+    let team = standing
+        .and_then(|s| s.team.as_deref())
+        .or(latest.and_then(|l| l.team.as_deref()))
+        .unwrap_or("-");
+
+    info_table(
+        &name,
+        vec![
+            (
+                "Championship",
+                pp_optional(standing.map(|s| format!("P{}", s.position))).into(),
+            ),
+            ("Points", pp_optional(standing.map(|s| s.points)).into()),
+            (
+                "Nationality",
+                pp_optional(standing.and_then(|s| s.nationality.as_deref())).into(),
+            ),
+            ("Team", Cell::colored(team, team_color(team))),
+            (
+                "Car number",
+                pp_optional(latest.and_then(|l| l.number)).into(),
+            ),
+        ],
+    )
+    .render(output)?;
+
+    let mut table = Table::new()
+        .title("This season")
+        .column("#", Align::Right)
+        .column("Grand Prix", Align::Left)
+        .column("Quali", Align::Right)
+        .column("Finish", Align::Right)
+        .column("Points", Align::Right);
+    for race in races {
+        let Some(result) = race.results.iter().find(|d| d.name == name) else {
+            continue;
+        };
+        let quali = qualifying
+            .iter()
+            .find(|q| q.round == race.round)
+            .and_then(|q| q.results.iter().find(|d| d.name == name))
+            .map(|d| d.position)
+            .filter(|p| *p != 0);
+        table.add_row(vec![
+            race.round.into(),
+            race.gp_name.as_str().into(),
+            pp_optional(quali).into(),
+            result.pp_position().into(),
+            result.points.into(),
+        ]);
+    }
+    output.push('\n');
+    table.render(output)
+}
+
+pub fn pp_team_profile(
+    query: &str,
+    team_standings: &[PositionInfo],
+    driver_standings: &[PositionInfo],
+    races: &[CompletedRace],
+    output: &mut String,
+) -> Result<()> {
+    let names = team_standings.iter().map(|t| t.name.as_str()).chain(
+        races
+            .iter()
+            .flat_map(|r| r.results.iter().filter_map(|d| d.team.as_deref())),
+    );
+    let name = find_team_name(names, query)?;
+    let standing = team_standings.iter().find(|t| same_team(&t.name, &name));
+
+    info_table(
+        &name,
+        vec![
+            (
+                "Championship",
+                pp_optional(standing.map(|s| format!("P{}", s.position))).into(),
+            ),
+            ("Points", pp_optional(standing.map(|s| s.points)).into()),
+        ],
+    )
+    .render(output)?;
+
+    // drivers of the team in standings order, drivers who stood in for a race
+    // only show up in race results
+    let mut drivers = driver_standings
+        .iter()
+        .filter(|d| d.team.as_deref().is_some_and(|t| same_team(t, &name)))
+        .collect::<Vec<_>>();
+    let mut table = Table::new()
+        .title("Drivers")
+        .column("Driver", Align::Left)
+        .column("Number", Align::Right)
+        .column("Nationality", Align::Left)
+        .column("Pos", Align::Right)
+        .column("Points", Align::Right);
+    for race in races {
+        for result in &race.results {
+            let is_team_driver = result.team.as_deref().is_some_and(|t| same_team(t, &name));
+            if is_team_driver && !drivers.iter().any(|d| d.name == result.name) {
+                drivers.push(result);
+            }
+        }
+    }
+    for driver in &drivers {
+        let number = races
+            .iter()
+            .rev()
+            .find_map(|r| r.results.iter().find(|d| d.name == driver.name))
+            .and_then(|d| d.number);
+        let standing = driver_standings.iter().find(|d| d.name == driver.name);
+        table.add_row(vec![
+            driver.name.as_str().into(),
+            pp_optional(number).into(),
+            pp_optional(standing.and_then(|s| s.nationality.as_deref())).into(),
+            pp_optional(standing.map(|s| s.position)).into(),
+            pp_optional(standing.map(|s| s.points)).into(),
+        ]);
+    }
+    output.push('\n');
+    table.render(output)?;
+
+    let mut table = Table::new()
+        .title("This season")
+        .column("#", Align::Right)
+        .column("Grand Prix", Align::Left)
+        .column("Results", Align::Left)
+        .column("Points", Align::Right);
+    for race in races {
+        let results = race
+            .results
+            .iter()
+            .filter(|d| d.team.as_deref().is_some_and(|t| same_team(t, &name)))
+            .collect::<Vec<_>>();
+        if results.is_empty() {
+            continue;
+        }
+        let pp_results = results
+            .iter()
+            .map(|d| format!("{} {}", driver_code(&d.name), d.pp_position()))
+            .collect::<Vec<_>>();
+        table.add_row(vec![
+            race.round.into(),
+            race.gp_name.as_str().into(),
+            pp_results.join(", ").into(),
+            results.iter().map(|d| d.points).sum::<usize>().into(),
+        ]);
+    }
+    output.push('\n');
+    table.render(output)
+}
@@ -1,98 +1,30 @@
 use serde::{Deserialize, Serialize};
-use std::fmt::Write;
 use std::path::Path;
-use std::sync::{Arc, Mutex};
-use std::thread::JoinHandle;
 
 use crate::error::{Error, Result};
-use crate::utils::{DataFetcher, QualiPositionInfo, F1_TABLE_SELECTOR};
-use crate::CURR_YEAR;
+use crate::laptime::{pp_gap, LapTime};
+use crate::table::{team_color, Align, Cell, Table};
+use crate::utils::{
+    cell_text, fetch_round_pages, DataFetcher, QualiPositionInfo, QualiSession, F1_TABLE_SELECTOR,
+};
 
 const BASE_URL: &str = "https://www.formula1.com";
+// Drivers reaching Q3, unchanged since 2008
+const Q3_DRIVERS: usize = 10;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CompletedQualifying {
-    round: usize,
-    gp_name: String,
-    results: Vec<QualiPositionInfo>,
-}
-
-fn fetch_data(url: &str) -> Result<String> {
-    let body: String = ureq::get(url).call()?.into_string()?;
-    Ok(body)
-}
-
-pub fn parse_qualifying_page(
-    html: String,
-    existing_round_results: Vec<usize>,
-) -> Result<Vec<CompletedQualifying>> {
-    let document = scraper::Html::parse_document(&html);
-    // constructing all selectors
-    let table_selector = scraper::Selector::parse(F1_TABLE_SELECTOR).map_err(|_| Error::Scraper)?;
-    let anchor_selector = scraper::Selector::parse("a").map_err(|_| Error::Scraper)?;
-    let td_selector = scraper::Selector::parse("td").map_err(|_| Error::Scraper)?;
-
-    let table_body = document.select(&table_selector);
-
-    let mut join_handles: Vec<JoinHandle<Result<()>>> = Vec::new();
-    let output_data = Arc::new(Mutex::new(Vec::new()));
-
-    for (idx, element) in table_body.enumerate() {
-        let output_arc_clone = output_data.clone();
-        if existing_round_results.contains(&(idx + 1)) {
-            // why? We don't want to refetch results for Grand Prix already in cache
-            // If cached data is corrupted do f1gp clean and f1gp pull or
-            // remove cached file in `tmp` location.
-            continue;
-        }
-
-        let mut iter = element.select(&td_selector);
-
-        let td_link = iter.next().ok_or_else(|| Error::ParseRaceResults)?;
-        let link = td_link
-            .select(&anchor_selector)
-            .next()
-            .ok_or_else(|| Error::ParseRaceResults)?
-            .value()
-            .attr("href")
-            .ok_or_else(|| Error::ParseRaceResults)?
-            .to_owned();
-        let gp_name = td_link.text().collect::<Vec<_>>()[1].trim().to_owned();
-        let race_url = format!("{}/{}", BASE_URL, link);
-        let quali_url = race_url.replace("race-result", "qualifying");
-
-        let handle = std::thread::spawn(move || {
-            println!("Fetching Qualifying data from {}", &quali_url);
-            let body = fetch_data(&quali_url)?;
-            let quali_result = fetch_parse_individual_quali_result(body)?;
-            let mut guraded_data = output_arc_clone
-                .lock()
-                .map_err(|_| Error::ParseRaceResults)?;
-            guraded_data.push(CompletedQualifying {
-                round: idx + 1,
-                gp_name,
-                results: quali_result,
-            });
-            Ok(())
-        });
-        join_handles.push(handle);
-    }
-
-    for h in join_handles.into_iter() {
-        let _ = h.join().map_err(|_| Error::ParseRaceResults)?;
-    }
-
-    let lock = Arc::into_inner(output_data).ok_or(Error::ParseRaceResults)?;
-    let mut output_data = lock.into_inner().map_err(|_| Error::ParseRaceResults)?;
-    output_data.sort_by(|a, b| a.round.cmp(&b.round));
-    Ok(output_data)
+    pub round: usize,
+    pub gp_name: String,
+    pub results: Vec<QualiPositionInfo>,
 }
 
 fn fetch_parse_individual_quali_result(document: String) -> Result<Vec<QualiPositionInfo>> {
     let document = scraper::Html::parse_document(&document);
-    let f1_table_selector = scraper::Selector::parse(F1_TABLE_SELECTOR).unwrap();
+    let f1_table_selector =
+        scraper::Selector::parse(F1_TABLE_SELECTOR).map_err(|_| Error::Scraper)?;
 
-    let td_selector = scraper::Selector::parse("td").unwrap();
+    let td_selector = scraper::Selector::parse("td").map_err(|_| Error::Scraper)?;
 
     let doc_iter = document.select(&f1_table_selector);
 
@@ -101,33 +33,40 @@ fn fetch_parse_individual_quali_result(document: String) -> Result<Vec<QualiPosi
     for element in doc_iter {
         let mut element_iter = element.select(&td_selector);
 
-        let position = element_iter.next().unwrap().text().collect::<Vec<_>>()[0]
+        let position = cell_text(element_iter.next().ok_or(Error::ParseRaceResults)?)
             .parse::<usize>()
             .unwrap_or(0);
 
         // skip car number
         element_iter.next();
 
-        let full_name = element_iter.next().unwrap().text().collect::<Vec<_>>();
-        let mut name = full_name[0].to_owned();
-        name.push(' ');
-        name.push_str(full_name[2]);
-
-        // skip team name
-        element_iter.next();
-
-        let q = element_iter.next().unwrap().text().collect::<Vec<_>>();
-        let q1 = q.first().map(|s| s.to_owned().to_owned());
+        let full_name = element_iter
+            .next()
+            .ok_or(Error::ParseRaceResults)?
+            .text()
+            .collect::<Vec<_>>();
+        let (Some(first), Some(last)) = (full_name.first(), full_name.get(2)) else {
+            return Err(Error::ParseRaceResults);
+        };
+        let name = format!("{first} {last}");
 
-        let q = element_iter.next().unwrap().text().collect::<Vec<_>>();
-        let q2 = q.first().map(|s| s.to_owned().to_owned());
+        let team = element_iter.next().map(cell_text);
 
-        let q = element_iter.next().unwrap().text().collect::<Vec<_>>();
-        let q3 = q.first().map(|s| s.to_owned().to_owned());
+        // cells without a time are either empty, have DNF/DNS or are missing
+        // altogether for drivers who did not take part
+        let mut next_time = || {
+            element_iter
+                .next()
+                .and_then(|td| cell_text(td).parse::<LapTime>().ok())
+        };
+        let q1 = next_time();
+        let q2 = next_time();
+        let q3 = next_time();
 
         let quali_result = QualiPositionInfo {
             position,
             name,
+            team,
             q1,
             q2,
             q3,
@@ -140,13 +79,13 @@ fn fetch_parse_individual_quali_result(document: String) -> Result<Vec<QualiPosi
 impl DataFetcher for CompletedQualifying {
     type A = Vec<CompletedQualifying>;
 
-    fn cache_file_name() -> String {
-        format!("{}_quali_results.json", *CURR_YEAR)
+    fn cache_file_name(year: i32) -> String {
+        format!("{}_quali_results.json", year)
     }
 
-    fn resource_url() -> String {
+    fn resource_url(year: i32) -> String {
         println!("Fetching data for all completed Qualifying Prix");
-        let calendar_race_results = format!("en/results/{}/races", *CURR_YEAR);
+        let calendar_race_results = format!("en/results/{}/races", year);
         format!("{}/{}", BASE_URL, calendar_race_results)
     }
 
@@ -157,31 +96,232 @@ impl DataFetcher for CompletedQualifying {
             Vec::new()
         };
         let rounds_cached = all_results.iter().map(|r| r.round).collect::<Vec<usize>>();
-        all_results.extend(parse_qualifying_page(raw_data, rounds_cached)?);
+        let fetched = fetch_round_pages(
+            raw_data,
+            rounds_cached,
+            "qualifying",
+            fetch_parse_individual_quali_result,
+        )?;
+        all_results.extend(fetched.into_iter().map(|page| CompletedQualifying {
+            round: page.round,
+            gp_name: page.gp_name,
+            results: page.data,
+        }));
+        all_results.sort_by_key(|r| r.round);
         Ok(all_results)
     }
 }
 
 impl CompletedQualifying {
+    // Q1 & Q2 knock out the same number of drivers, e.g. 15 of 20 or 16 of 22
+    // drivers reach Q2
+    fn q2_drivers(&self) -> usize {
+        let field = self.results.len();
+        field - field.saturating_sub(Q3_DRIVERS) / 2
+    }
+
+    // Last session the driver took part in. Taken from classified position as a
+    // driver can reach a session without setting a time, e.g. after a crash
+    pub fn last_session(&self, driver: &QualiPositionInfo) -> QualiSession {
+        match driver.position {
+            // not classified
+            0 => QualiSession::Q1,
+            p if p <= Q3_DRIVERS => QualiSession::Q3,
+            p if p <= self.q2_drivers() => QualiSession::Q2,
+            _ => QualiSession::Q1,
+        }
+    }
+
+    // Session the driver was knocked out in, `None` if they made it to Q3
+    pub fn eliminated_in(&self, driver: &QualiPositionInfo) -> Option<QualiSession> {
+        match self.last_session(driver) {
+            QualiSession::Q3 => None,
+            session => Some(session),
+        }
+    }
+
+    // Time set in the last session the driver took part in
+    pub fn session_time(&self, driver: &QualiPositionInfo) -> Option<LapTime> {
+        driver.time_in(self.last_session(driver))
+    }
+
+    // Gap between `driver` & `other` in the last session `driver` took part in.
+    // Times of different sessions are not comparable due to track evolution
+    pub fn gap_between(
+        &self,
+        driver: &QualiPositionInfo,
+        other: &QualiPositionInfo,
+    ) -> Option<i64> {
+        let session = self.last_session(driver);
+        Some(driver.time_in(session)?.gap_to(&other.time_in(session)?))
+    }
+
+    pub fn pole_sitter(&self) -> Option<&QualiPositionInfo> {
+        self.results.iter().find(|d| d.position == 1)
+    }
+
+    pub fn pole_time(&self) -> Option<LapTime> {
+        self.pole_sitter()?.q3
+    }
+
+    // Slowest time of `session` which still made it into the next session
+    fn cutoff(&self, session: QualiSession) -> Option<LapTime> {
+        self.results
+            .iter()
+            .filter(|d| self.last_session(d) > session)
+            .filter_map(|d| d.time_in(session))
+            .max()
+    }
+
+    pub fn q1_cutoff(&self) -> Option<LapTime> {
+        self.cutoff(QualiSession::Q1)
+    }
+
+    pub fn q2_cutoff(&self) -> Option<LapTime> {
+        self.cutoff(QualiSession::Q2)
+    }
+
     pub fn pp_completed_quali_results(&self, output: &mut String) -> Result<()> {
-        writeln!(output, "{}", "-".repeat(self.gp_name.len()))?;
-        writeln!(output, "{}", self.gp_name)?;
-        writeln!(output, "{}", "-".repeat(self.gp_name.len()))?;
-        writeln!(
-            output,
-            "{:<3} {:<20} {:^8} | {:^8} | {:^8}",
-            "", "", "Q1", "Q2", "Q3"
-        )?;
+        let pp_time = |time: Option<LapTime>| time.map(|t| t.to_string()).unwrap_or_default();
+
+        let mut table = Table::new()
+            .title(&self.gp_name)
+            .column("Pos", Align::Right)
+            .column("Driver", Align::Left)
+            .column("Team", Align::Left)
+            .column("Q1", Align::Center)
+            .column("Q2", Align::Center)
+            .column("Q3", Align::Center)
+            .column("To Pole", Align::Right)
+            .column("Interval", Align::Right)
+            .column("Out", Align::Center);
+
+        let pole_sitter = self.pole_sitter();
+        let mut ahead: Option<&QualiPositionInfo> = None;
         for driver in &self.results {
-            let d_q1 = driver.q1.clone().unwrap_or("".to_owned());
-            let d_q2 = driver.q2.clone().unwrap_or("".to_owned());
-            let d_q3 = driver.q3.clone().unwrap_or("".to_owned());
-            writeln!(
-                output,
-                "{:<3} {:<20} {:<8} | {:<8} | {:<8}",
-                driver.position, driver.name, d_q1, d_q2, d_q3
-            )?;
+            let team = driver.team.as_deref().unwrap_or_default();
+            // no gaps for the pole sitter, i.e. nobody ahead
+            let gap_to = |other: Option<&QualiPositionInfo>| match (other, ahead) {
+                (Some(other), Some(_)) => self.gap_between(driver, other).map(pp_gap),
+                _ => None,
+            };
+            table.add_row(vec![
+                driver.position.into(),
+                driver.name.as_str().into(),
+                Cell::colored(team, team_color(team)),
+                pp_time(driver.q1).into(),
+                pp_time(driver.q2).into(),
+                pp_time(driver.q3).into(),
+                gap_to(pole_sitter).unwrap_or_default().into(),
+                gap_to(ahead).unwrap_or_default().into(),
+                self.eliminated_in(driver)
+                    .map(|s| s.name())
+                    .unwrap_or_default()
+                    .into(),
+            ]);
+            ahead = Some(driver);
         }
-        Ok(())
+        table.add_footer("Gaps are between times of the last session each driver took part in");
+        table.render(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Field of `size` drivers where everyone set a time in every session
+    // they took part in, P1 being the fastest
+    fn qualifying(size: usize) -> CompletedQualifying {
+        let time =
+            |base: u64, position: usize| Some(LapTime::from_millis(base + position as u64 * 100));
+        let mut quali = CompletedQualifying {
+            round: 1,
+            gp_name: "Test Grand Prix".to_owned(),
+            results: (1..=size)
+                .map(|position| QualiPositionInfo {
+                    position,
+                    name: format!("Driver {position}"),
+                    team: None,
+                    q1: time(91_000, position),
+                    q2: time(90_000, position),
+                    q3: time(89_000, position),
+                })
+                .collect(),
+        };
+        let sessions = quali
+            .results
+            .iter()
+            .map(|d| quali.last_session(d))
+            .collect::<Vec<_>>();
+        for (driver, session) in quali.results.iter_mut().zip(sessions) {
+            if session < QualiSession::Q3 {
+                driver.q3 = None;
+            }
+            if session < QualiSession::Q2 {
+                driver.q2 = None;
+            }
+        }
+        quali
+    }
+
+    #[test]
+    fn eliminated_in_from_position() {
+        let quali = qualifying(20);
+        let eliminated = |position: usize| quali.eliminated_in(&quali.results[position - 1]);
+        assert_eq!(eliminated(10), None);
+        assert_eq!(eliminated(11), Some(QualiSession::Q2));
+        assert_eq!(eliminated(15), Some(QualiSession::Q2));
+        assert_eq!(eliminated(16), Some(QualiSession::Q1));
+
+        // 22 car field knocks out 6 drivers in Q1 & Q2
+        let quali = qualifying(22);
+        let eliminated = |position: usize| quali.eliminated_in(&quali.results[position - 1]);
+        assert_eq!(eliminated(16), Some(QualiSession::Q2));
+        assert_eq!(eliminated(17), Some(QualiSession::Q1));
+    }
+
+    #[test]
+    fn reaching_q3_without_time() {
+        let mut quali = qualifying(20);
+        // crashed in Q3 & did not set a time
+        quali.results[9].q3 = None;
+        assert_eq!(quali.eliminated_in(&quali.results[9]), None);
+        assert_eq!(quali.session_time(&quali.results[9]), None);
+        // unclassified drivers did not make it past Q1
+        quali.results[19].position = 0;
+        assert_eq!(
+            quali.eliminated_in(&quali.results[19]),
+            Some(QualiSession::Q1)
+        );
+    }
+
+    #[test]
+    fn gaps_within_last_session() {
+        let quali = qualifying(20);
+        let pole_sitter = quali.pole_sitter().unwrap();
+        assert_eq!(quali.pole_time(), Some(LapTime::from_millis(89_100)));
+        // P2 in Q3, P12 & P18 in Q2 & Q1 respectively
+        assert_eq!(quali.gap_between(&quali.results[1], pole_sitter), Some(100));
+        assert_eq!(
+            quali.gap_between(&quali.results[11], pole_sitter),
+            Some(1100)
+        );
+        assert_eq!(
+            quali.gap_between(&quali.results[17], pole_sitter),
+            Some(1700)
+        );
+    }
+
+    #[test]
+    fn cutoffs_from_position() {
+        let mut quali = qualifying(20);
+        assert_eq!(quali.q1_cutoff(), Some(LapTime::from_millis(92_500)));
+        assert_eq!(quali.q2_cutoff(), Some(LapTime::from_millis(91_000)));
+        // P10 reached Q3 without a time, P16 was knocked out despite a Q2 time
+        quali.results[9].q3 = None;
+        quali.results[15].q2 = Some(LapTime::from_millis(90_000));
+        assert_eq!(quali.q1_cutoff(), Some(LapTime::from_millis(92_500)));
+        assert_eq!(quali.q2_cutoff(), Some(LapTime::from_millis(91_000)));
     }
 }
@@ -0,0 +1,199 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use crate::error::Result;
+use crate::grid::{positions_gained, pp_positions_change, StartingGrid};
+use crate::quali::CompletedQualifying;
+use crate::results::CompletedRace;
+use crate::table::{Align, Table};
+use crate::utils::{find_driver_name, PositionInfo};
+
+const BEST_RECOVERIES_SHOWN: usize = 5;
+
+// A driver's start & race result of one race
+struct RaceEntry<'a> {
+    round: usize,
+    gp_name: &'a str,
+    start: usize,
+    result: &'a PositionInfo,
+}
+
+struct Recovery<'a> {
+    name: &'a str,
+    gp_name: &'a str,
+    start: usize,
+    finish: usize,
+    gained: i64,
+}
+
+fn average(positions: &[usize]) -> String {
+    if positions.is_empty() {
+        return "-".to_owned();
+    }
+    format!(
+        "{:.1}",
+        positions.iter().sum::<usize>() as f64 / positions.len() as f64
+    )
+}
+
+// Start position comes from the starting grid when cached, otherwise from
+// qualifying which ignores grid penalties
+pub fn start_position(
+    name: &str,
+    grid: Option<&StartingGrid>,
+    quali: Option<&CompletedQualifying>,
+) -> Option<usize> {
+    if let Some(grid) = grid {
+        return grid
+            .grid
+            .iter()
+            .find(|d| d.name == name)
+            .map(|d| d.position);
+    }
+    quali
+        .and_then(|q| q.results.iter().find(|d| d.name == name))
+        .map(|d| d.position)
+        .filter(|p| *p != 0)
+}
+
+fn driver_races<'a>(
+    races: &'a [CompletedRace],
+    qualifying: &[CompletedQualifying],
+    grids: &[StartingGrid],
+) -> BTreeMap<&'a str, Vec<RaceEntry<'a>>> {
+    let mut drivers: BTreeMap<&str, Vec<RaceEntry>> = BTreeMap::new();
+    for race in races {
+        let grid = grids.iter().find(|g| g.round == race.round);
+        let quali = qualifying.iter().find(|q| q.round == race.round);
+        for result in &race.results {
+            let Some(start) = start_position(&result.name, grid, quali) else {
+                continue;
+            };
+            drivers.entry(&result.name).or_default().push(RaceEntry {
+                round: race.round,
+                gp_name: &race.gp_name,
+                start,
+                result,
+            });
+        }
+    }
+    drivers
+}
+
+// Season totals of every driver along with the best recoveries. Averages only
+// count races the driver was classified in, so start & finish are comparable
+pub fn pp_positions_gained(
+    races: &[CompletedRace],
+    qualifying: &[CompletedQualifying],
+    grids: &[StartingGrid],
+    output: &mut String,
+) -> Result<()> {
+    let drivers = driver_races(races, qualifying, grids);
+
+    let mut table = Table::new()
+        .title("POSITIONS GAINED")
+        .column("Driver", Align::Left)
+        .column("Races", Align::Right)
+        .column("Gained", Align::Right)
+        .column("Avg Start", Align::Right)
+        .column("Avg Finish", Align::Right)
+        .column("Not Classified", Align::Right);
+
+    let mut recoveries: Vec<Recovery> = Vec::new();
+    let mut totals = Vec::new();
+    for (name, entries) in &drivers {
+        let classified = entries
+            .iter()
+            .filter(|e| e.result.position != 0)
+            .collect::<Vec<_>>();
+        let gained = classified
+            .iter()
+            .filter_map(|e| positions_gained(e.start, e.result.position))
+            .sum::<i64>();
+        for entry in &classified {
+            if let Some(gained) = positions_gained(entry.start, entry.result.position) {
+                recoveries.push(Recovery {
+                    name,
+                    gp_name: entry.gp_name,
+                    start: entry.start,
+                    finish: entry.result.position,
+                    gained,
+                });
+            }
+        }
+        totals.push((*name, entries.len(), gained, classified));
+    }
+
+    totals.sort_by_key(|(_, _, gained, _)| Reverse(*gained));
+    for (name, race_count, gained, classified) in totals {
+        let starts = classified.iter().map(|e| e.start).collect::<Vec<_>>();
+        let finishes = classified
+            .iter()
+            .map(|e| e.result.position)
+            .collect::<Vec<_>>();
+        table.add_row(vec![
+            name.into(),
+            race_count.into(),
+            pp_positions_change(Some(gained)),
+            average(&starts).into(),
+            average(&finishes).into(),
+            (race_count - classified.len()).into(),
+        ]);
+    }
+    if grids.is_empty() {
+        table.add_footer("No starting grids cached, qualifying positions are used as start");
+    }
+    table.render(output)?;
+
+    recoveries.sort_by_key(|r| Reverse(r.gained));
+    let mut best = Table::new()
+        .title("BEST RECOVERIES")
+        .column("Driver", Align::Left)
+        .column("Grand Prix", Align::Left)
+        .column("Start", Align::Right)
+        .column("Finish", Align::Right)
+        .column("+/-", Align::Right);
+    for recovery in recoveries.iter().take(BEST_RECOVERIES_SHOWN) {
+        best.add_row(vec![
+            recovery.name.into(),
+            recovery.gp_name.into(),
+            recovery.start.into(),
+            recovery.finish.into(),
+            pp_positions_change(Some(recovery.gained)),
+        ]);
+    }
+    output.push('\n');
+    best.render(output)
+}
+
+// Start, finish & positions gained of a single driver in every race
+pub fn pp_driver_positions_gained(
+    races: &[CompletedRace],
+    qualifying: &[CompletedQualifying],
+    grids: &[StartingGrid],
+    query: &str,
+    output: &mut String,
+) -> Result<()> {
+    let drivers = driver_races(races, qualifying, grids);
+    let name = find_driver_name(drivers.keys().copied(), query)?;
+    let mut table = Table::new()
+        .title(format!("{name} - Positions Gained"))
+        .column("#", Align::Right)
+        .column("Grand Prix", Align::Left)
+        .column("Start", Align::Right)
+        .column("Finish", Align::Right)
+        .column("+/-", Align::Right);
+    for entry in drivers.get(name.as_str()).into_iter().flatten() {
+        table.add_row(vec![
+            entry.round.into(),
+            entry.gp_name.into(),
+            entry.start.into(),
+            entry.result.pp_position().into(),
+            pp_positions_change(positions_gained(entry.start, entry.result.position)),
+        ]);
+    }
+    if grids.is_empty() {
+        table.add_footer("No starting grids cached, qualifying positions are used as start");
+    }
+    table.render(output)
+}
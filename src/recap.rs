@@ -0,0 +1,220 @@
+use std::fmt::{Display, Write};
+
+use crate::error::{Error, Result};
+use crate::laptime::pp_gap;
+use crate::quali::CompletedQualifying;
+use crate::results::CompletedRace;
+use crate::schedule::{GrandPrix, STR_FMT};
+use crate::sprint::CompletedSprint;
+use crate::standings::{pp_position_change, standings_movement};
+use crate::table::{display_width, team_color, Align, Cell, Table};
+use crate::utils::PositionInfo;
+
+// drivers shown for qualifying & standings, the race is shown in full
+const TOP_N: usize = 10;
+
+#[derive(Clone, Copy)]
+pub enum RecapFormat {
+    Markdown,
+    Text,
+}
+
+impl RecapFormat {
+    pub fn from_arg(arg: &str) -> Result<Self> {
+        match arg.to_lowercase().as_str() {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "text" | "txt" => Ok(Self::Text),
+            _ => Err(Error::InvalidArgs),
+        }
+    }
+
+    fn write_heading(&self, output: &mut String, level: usize, heading: &str) -> Result<()> {
+        match self {
+            Self::Markdown => writeln!(output, "{} {}\n", "#".repeat(level), heading)?,
+            Self::Text => {
+                let underline = if level == 1 { "=" } else { "-" };
+                writeln!(
+                    output,
+                    "{}\n{}\n",
+                    heading,
+                    underline.repeat(display_width(heading))
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_fact(&self, output: &mut String, label: &str, value: impl Display) -> Result<()> {
+        match self {
+            Self::Markdown => writeln!(output, "- **{label}:** {value}")?,
+            Self::Text => writeln!(output, "{label}: {value}")?,
+        }
+        Ok(())
+    }
+
+    fn write_table(&self, output: &mut String, table: &Table) -> Result<()> {
+        match self {
+            Self::Markdown => table.render_markdown(output)?,
+            Self::Text => table.render(output)?,
+        }
+        writeln!(output)?;
+        Ok(())
+    }
+}
+
+fn team_cell(team: Option<&str>) -> Cell {
+    let team = team.unwrap_or_default();
+    Cell::colored(team, team_color(team))
+}
+
+fn quali_table(quali: &CompletedQualifying) -> Table {
+    let mut table = Table::new()
+        .column("Pos", Align::Right)
+        .column("Driver", Align::Left)
+        .column("Team", Align::Left)
+        .column("Time", Align::Right)
+        .column("Gap", Align::Right);
+    let pole_sitter = quali.pole_sitter();
+    for driver in quali.results.iter().take(TOP_N) {
+        let session_time = quali.session_time(driver);
+        let gap = match pole_sitter {
+            Some(pole_sitter) if driver.position != 1 => quali
+                .gap_between(driver, pole_sitter)
+                .map(pp_gap)
+                .unwrap_or_default(),
+            _ => String::new(),
+        };
+        table.add_row(vec![
+            driver.position.into(),
+            driver.name.as_str().into(),
+            team_cell(driver.team.as_deref()),
+            session_time
+                .map(|t| t.to_string())
+                .unwrap_or_default()
+                .into(),
+            gap.into(),
+        ]);
+    }
+    table
+}
+
+// Used for both the race & the sprint
+fn race_table(results: &[PositionInfo]) -> Table {
+    let mut table = Table::new()
+        .column("Pos", Align::Right)
+        .column("Driver", Align::Left)
+        .column("Team", Align::Left)
+        .column("Points", Align::Right)
+        .column("Note", Align::Left);
+    for driver in results {
+        table.add_row(vec![
+            driver.pp_position().into(),
+            driver.name.as_str().into(),
+            team_cell(driver.team.as_deref()),
+            driver.points.into(),
+            driver.reason.as_deref().unwrap_or_default().into(),
+        ]);
+    }
+    table
+}
+
+// Season results the sprint & standings movement are taken from
+pub struct Season<'a> {
+    pub races: &'a [CompletedRace],
+    pub sprints: &'a [CompletedSprint],
+    pub driver_standings: &'a [PositionInfo],
+}
+
+fn standings_table(season: &Season, round: usize) -> Table {
+    let mut table = Table::new()
+        .column("Pos", Align::Right)
+        .column("+/-", Align::Right)
+        .column("Driver", Align::Left)
+        .column("Points", Align::Right)
+        .column("Gained", Align::Right);
+    for movement in standings_movement(season.driver_standings, season.races, season.sprints, round)
+        .into_iter()
+        .take(TOP_N)
+    {
+        table.add_row(vec![
+            movement.position.into(),
+            pp_position_change(movement.change),
+            movement.entry.name.as_str().into(),
+            movement.points.into(),
+            format!("+{}", movement.gained).into(),
+        ]);
+    }
+    table
+}
+
+// Weekend report for sharing, e.g. in a chat channel. Every section is optional
+// except the race result
+pub fn pp_recap(
+    gp: &GrandPrix,
+    race: &CompletedRace,
+    quali: Option<&CompletedQualifying>,
+    season: &Season,
+    format: RecapFormat,
+    output: &mut String,
+) -> Result<()> {
+    format.write_heading(
+        output,
+        1,
+        &format!("Round {}: {}", race.round, gp.gp_name()),
+    )?;
+    format.write_fact(output, "Location", gp.location())?;
+    format.write_fact(output, "Race start", gp.gp_start_dt().format(STR_FMT))?;
+    let sprint = season.sprints.iter().find(|s| s.round == race.round);
+    if let Some(winner) = race.results.iter().find(|d| d.position == 1) {
+        format.write_fact(output, "Winner", &winner.name)?;
+    }
+    if let Some(winner) = sprint.and_then(|s| s.results.iter().find(|d| d.position == 1)) {
+        format.write_fact(output, "Sprint winner", &winner.name)?;
+    }
+    if let Some(pole) = quali.and_then(|q| q.results.iter().find(|d| d.position == 1)) {
+        format.write_fact(output, "Pole", &pole.name)?;
+    }
+    writeln!(output)?;
+
+    if let Some(quali) = quali {
+        format.write_heading(output, 2, &format!("Qualifying - Top {TOP_N}"))?;
+        format.write_table(output, &quali_table(quali))?;
+    }
+
+    if let Some(sprint) = sprint {
+        format.write_heading(output, 2, "Sprint")?;
+        format.write_table(output, &race_table(&sprint.results))?;
+    } else if gp.is_sprint_weekend() {
+        format.write_heading(output, 2, "Sprint")?;
+        writeln!(
+            output,
+            "Sprint results are not available, run `f1gp pull` to fetch them\n"
+        )?;
+    }
+
+    format.write_heading(output, 2, "Race")?;
+    format.write_table(output, &race_table(&race.results))?;
+
+    if !season.driver_standings.is_empty() {
+        format.write_heading(output, 2, "Driver Standings")?;
+        format.write_table(output, &standings_table(season, race.round))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_underline_matches_heading_width() {
+        let mut output = String::new();
+        RecapFormat::Text
+            .write_heading(&mut output, 1, "São Paulo Grand Prix")
+            .unwrap();
+        assert_eq!(
+            output,
+            format!("São Paulo Grand Prix\n{}\n\n", "=".repeat(20))
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use crate::error::Result;
+use crate::results::CompletedRace;
+use crate::table::{team_color, Align, Cell, Table};
+use crate::utils::RaceStatus;
+
+const MECHANICAL_KEYWORDS: [&str; 24] = [
+    "engine",
+    "power unit",
+    "power loss",
+    "gearbox",
+    "transmission",
+    "clutch",
+    "hydraulic",
+    "brake",
+    "suspension",
+    "electrical",
+    "electronics",
+    "ers",
+    "energy store",
+    "mgu",
+    "turbo",
+    "oil",
+    "water",
+    "fuel",
+    "cooling",
+    "overheating",
+    "exhaust",
+    "driveshaft",
+    "steering",
+    "mechanical",
+];
+const ACCIDENT_KEYWORDS: [&str; 7] = [
+    "accident",
+    "collision",
+    "crash",
+    "spun",
+    "spin",
+    "contact",
+    "damage",
+];
+
+#[derive(Debug, PartialEq)]
+enum RetirementCause {
+    Mechanical,
+    Accident,
+    Unknown,
+}
+
+// Most results only say `DNF`, the cause is known only when a reason is published
+fn retirement_cause(reason: Option<&str>) -> RetirementCause {
+    let Some(reason) = reason.map(|r| r.to_lowercase()) else {
+        return RetirementCause::Unknown;
+    };
+    // match on word prefixes so `ers` doesn't match `others` but `brake` matches `brakes`
+    let has_keyword = |keywords: &[&str]| {
+        reason
+            .split(|c: char| !c.is_alphanumeric())
+            .any(|word| keywords.iter().any(|k| word.starts_with(k)))
+            || keywords
+                .iter()
+                .any(|k| k.contains(' ') && reason.contains(k))
+    };
+    if has_keyword(&ACCIDENT_KEYWORDS) {
+        RetirementCause::Accident
+    } else if has_keyword(&MECHANICAL_KEYWORDS) {
+        RetirementCause::Mechanical
+    } else {
+        RetirementCause::Unknown
+    }
+}
+
+#[derive(Default)]
+struct Reliability {
+    starts: usize,
+    finishes: usize,
+    // took the flag but covered too little race distance to be classified
+    not_classified: usize,
+    retirements: usize,
+    mechanical: usize,
+    accident: usize,
+    not_started: usize,
+    disqualified: usize,
+}
+
+impl Reliability {
+    fn add(&mut self, status: RaceStatus, reason: Option<&str>) {
+        match status {
+            RaceStatus::Finished => {
+                self.starts += 1;
+                self.finishes += 1;
+            }
+            RaceStatus::NotClassified => {
+                self.starts += 1;
+                self.not_classified += 1;
+            }
+            RaceStatus::Retired => {
+                self.starts += 1;
+                self.retirements += 1;
+                match retirement_cause(reason) {
+                    RetirementCause::Mechanical => self.mechanical += 1,
+                    RetirementCause::Accident => self.accident += 1,
+                    RetirementCause::Unknown => {}
+                }
+            }
+            RaceStatus::DidNotStart => self.not_started += 1,
+            RaceStatus::Disqualified => {
+                self.starts += 1;
+                self.disqualified += 1;
+            }
+        }
+    }
+}
+
+fn reliability_table(title: &str, is_team: bool, entries: BTreeMap<&str, Reliability>) -> Table {
+    let mut table = Table::new()
+        .title(title)
+        .column(if is_team { "Team" } else { "Driver" }, Align::Left)
+        .column("Starts", Align::Right)
+        .column("Finished", Align::Right)
+        .column("NC", Align::Right)
+        .column("DNF", Align::Right)
+        .column("Mechanical", Align::Right)
+        .column("Accident", Align::Right)
+        .column("DNS", Align::Right)
+        .column("DSQ", Align::Right);
+
+    let mut entries = entries.into_iter().collect::<Vec<_>>();
+    entries.sort_by_key(|(_, r)| {
+        Reverse(r.not_classified + r.retirements + r.not_started + r.disqualified)
+    });
+    for (name, r) in entries {
+        let color = if is_team { team_color(name) } else { None };
+        table.add_row(vec![
+            Cell::colored(name, color),
+            r.starts.into(),
+            r.finishes.into(),
+            r.not_classified.into(),
+            r.retirements.into(),
+            r.mechanical.into(),
+            r.accident.into(),
+            r.not_started.into(),
+            r.disqualified.into(),
+        ]);
+    }
+    table
+}
+
+pub fn pp_reliability(races: &[CompletedRace], output: &mut String) -> Result<()> {
+    let mut drivers: BTreeMap<&str, Reliability> = BTreeMap::new();
+    let mut teams: BTreeMap<&str, Reliability> = BTreeMap::new();
+    let mut missing_status = false;
+
+    for race in races {
+        for result in &race.results {
+            let Some(status) = result.status else {
+                missing_status = true;
+                continue;
+            };
+            let reason = result.reason.as_deref();
+            drivers.entry(&result.name).or_default().add(status, reason);
+            if let Some(team) = &result.team {
+                teams.entry(team).or_default().add(status, reason);
+            }
+        }
+    }
+
+    let mut table = reliability_table("DRIVER RELIABILITY", false, drivers);
+    table
+        .add_footer("Mechanical & Accident are counted only when a retirement reason is published");
+    if missing_status {
+        table.add_footer(
+            "Some cached results have no classification status. Run `f1gp clean` & `f1gp pull` to refetch",
+        );
+    }
+    table.render(output)?;
+
+    output.push('\n');
+    reliability_table("TEAM RELIABILITY", true, teams).render(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retirement_cause_from_reason() {
+        let cause = |reason| retirement_cause(Some(reason));
+        assert_eq!(cause("Engine"), RetirementCause::Mechanical);
+        assert_eq!(cause("Power Unit"), RetirementCause::Mechanical);
+        assert_eq!(cause("Brakes"), RetirementCause::Mechanical);
+        assert_eq!(cause("Hydraulics"), RetirementCause::Mechanical);
+        assert_eq!(cause("Collision damage"), RetirementCause::Accident);
+        assert_eq!(cause("Spun off"), RetirementCause::Accident);
+        assert_eq!(cause("Withdrew"), RetirementCause::Unknown);
+        // `ers` only matches as a word prefix
+        assert_eq!(cause("Other drivers"), RetirementCause::Unknown);
+        assert_eq!(retirement_cause(None), RetirementCause::Unknown);
+    }
+
+    #[test]
+    fn not_classified_is_not_a_finish() {
+        let mut reliability = Reliability::default();
+        reliability.add(RaceStatus::Finished, None);
+        reliability.add(RaceStatus::NotClassified, None);
+        reliability.add(RaceStatus::Retired, Some("Gearbox"));
+        assert_eq!(reliability.starts, 3);
+        assert_eq!(reliability.finishes, 1);
+        assert_eq!(reliability.not_classified, 1);
+        assert_eq!(reliability.mechanical, 1);
+    }
+}
@@ -1,11 +1,12 @@
 use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
-use std::fmt::Write;
+use std::io::Write;
 use std::path::Path;
+use std::time::Duration;
 
 use crate::error::Result;
+use crate::table::{Align, Cell, Color, Table};
 use crate::utils::DataFetcher;
-use crate::CURR_YEAR;
 
 // for date time formatting
 pub const STR_FMT: &str = "%a %d/%m/%Y %H:%M";
@@ -19,44 +20,44 @@ pub const SPR_QUALI: &str = "Spr Quali";
 pub const SPRINT: &str = "Sprint";
 pub const RACE: &str = "Race";
 
-fn pp_session(
-    session_name: &str,
-    session_dt: DateTime<Local>,
-    curr_dt: DateTime<Local>,
-    pad_session: usize,
-    line_width: usize,
-) -> String {
-    let is_past = if curr_dt > session_dt { "x" } else { " " };
-
-    format!(
-        "| {:<line_width$} |",
-        format!(
-            "[{}] {:<pad_session$}: {}",
-            is_past,
-            session_name,
-            session_dt.format(STR_FMT)
-        )
-    )
+fn session_status(session_dt: DateTime<Local>, curr_dt: DateTime<Local>) -> &'static str {
+    if curr_dt > session_dt {
+        "[x]"
+    } else {
+        "[ ]"
+    }
 }
 
-fn time_until_next_session(sessions: [&DateTime<Local>; 5], curr_dt: DateTime<Local>) -> String {
-    let mut next_session: Option<DateTime<Local>> = None;
-    for session_dt in sessions {
-        if curr_dt < *session_dt {
-            next_session = Some(*session_dt);
+// `with_seconds` is used by watch mode where the countdown is redrawn every second
+fn time_until_next_session(
+    sessions: &[(&str, DateTime<Local>)],
+    curr_dt: DateTime<Local>,
+    with_seconds: bool,
+) -> String {
+    let mut next_session: Option<&(&str, DateTime<Local>)> = None;
+    for session in sessions {
+        if curr_dt < session.1 {
+            next_session = Some(session);
             break;
         }
     }
 
     let mut output = String::new();
-    if let Some(dt) = next_session {
-        let y = dt - curr_dt;
+    if let Some((session_name, dt)) = next_session {
+        let y = *dt - curr_dt;
         output = format!(
             "Next session in: {} days, {} hours, {} minutes",
             y.num_days(),
             y.num_hours() % 24,
             y.num_minutes() % 60
         );
+        if with_seconds {
+            output.push_str(&format!(
+                ", {} seconds ({})",
+                y.num_seconds() % 60,
+                session_name
+            ));
+        }
     }
     output
 }
@@ -70,41 +71,14 @@ struct NormalWeekend {
     gp: DateTime<Local>,
 }
 impl NormalWeekend {
-    pub fn pp_normal(&self, output: &mut String, line_width: usize) -> Result<()> {
-        let session_width = 5;
-        let curr_dt = Local::now();
-        writeln!(
-            output,
-            "{}",
-            pp_session(FP1, self.fp1, curr_dt, session_width, line_width)
-        )?;
-        writeln!(
-            output,
-            "{}",
-            pp_session(FP2, self.fp2, curr_dt, session_width, line_width)
-        )?;
-        writeln!(
-            output,
-            "{}",
-            pp_session(FP3, self.fp3, curr_dt, session_width, line_width)
-        )?;
-        writeln!(
-            output,
-            "{}",
-            pp_session(QUALI, self.qualifying, curr_dt, session_width, line_width)
-        )?;
-        writeln!(
-            output,
-            "{}",
-            pp_session(RACE, self.gp, curr_dt, session_width, line_width)
-        )?;
-        Ok(())
-    }
-
-    fn pp_until_next_normal(&self) -> String {
-        let curr_dt = Local::now();
-        let sessions = [&self.fp1, &self.fp2, &self.fp3, &self.qualifying, &self.gp];
-        time_until_next_session(sessions, curr_dt)
+    fn list(&self) -> [(&'static str, DateTime<Local>); 5] {
+        [
+            (FP1, self.fp1),
+            (FP2, self.fp2),
+            (FP3, self.fp3),
+            (QUALI, self.qualifying),
+            (RACE, self.gp),
+        ]
     }
 }
 
@@ -118,53 +92,14 @@ struct SprintWeekend {
     gp: DateTime<Local>,
 }
 impl SprintWeekend {
-    fn pp_sprint(&self, output: &mut String, line_width: usize) -> Result<()> {
-        let session_width = 9;
-        let curr_dt = Local::now();
-        writeln!(
-            output,
-            "{}",
-            pp_session(FP1, self.fp1, curr_dt, session_width, line_width)
-        )?;
-        writeln!(
-            output,
-            "{}",
-            pp_session(
-                SPR_QUALI,
-                self.sprintQualifying,
-                curr_dt,
-                session_width,
-                line_width
-            )
-        )?;
-        writeln!(
-            output,
-            "{}",
-            pp_session(SPRINT, self.sprint, curr_dt, session_width, line_width)
-        )?;
-        writeln!(
-            output,
-            "{}",
-            pp_session(QUALI, self.qualifying, curr_dt, session_width, line_width)
-        )?;
-        writeln!(
-            output,
-            "{}",
-            pp_session(RACE, self.gp, curr_dt, session_width, line_width)
-        )?;
-        Ok(())
-    }
-
-    fn pp_until_next_sprint(&self) -> String {
-        let curr_dt = Local::now();
-        let sessions = [
-            &self.fp1,
-            &self.sprintQualifying,
-            &self.sprint,
-            &self.qualifying,
-            &self.gp,
-        ];
-        time_until_next_session(sessions, curr_dt)
+    fn list(&self) -> [(&'static str, DateTime<Local>); 5] {
+        [
+            (FP1, self.fp1),
+            (SPR_QUALI, self.sprintQualifying),
+            (SPRINT, self.sprint),
+            (QUALI, self.qualifying),
+            (RACE, self.gp),
+        ]
     }
 }
 
@@ -182,19 +117,17 @@ impl Sessions {
             Self::Sprint(sessions) => sessions.gp,
         }
     }
-    fn pp_time_until_next_session(&self) -> String {
+
+    // All sessions of the weekend in chronological order
+    pub fn list(&self) -> [(&'static str, DateTime<Local>); 5] {
         match self {
-            Self::Normal(sessions) => sessions.pp_until_next_normal(),
-            Self::Sprint(sessions) => sessions.pp_until_next_sprint(),
+            Self::Normal(sessions) => sessions.list(),
+            Self::Sprint(sessions) => sessions.list(),
         }
     }
 
-    fn pp_session(&self, output: &mut String, line_width: usize) -> Result<()> {
-        match self {
-            Self::Normal(ref session) => session.pp_normal(output, line_width),
-            Self::Sprint(ref session) => session.pp_sprint(output, line_width),
-        }?;
-        Ok(())
+    fn pp_time_until_next_session(&self, curr_dt: DateTime<Local>, with_seconds: bool) -> String {
+        time_until_next_session(&self.list(), curr_dt, with_seconds)
     }
 }
 
@@ -205,47 +138,139 @@ pub struct GrandPrix {
     sessions: Sessions,
 }
 impl GrandPrix {
-    pub fn pp_race_title(&self, curr_dt: DateTime<Local>, round: usize) -> String {
-        let race_name = format!("{} Grand Prix / {}", self.name, self.location);
-        let is_past = if curr_dt > self.sessions.gp_start_dt() {
+    // Some entries in the source already carry the "Grand Prix" suffix
+    pub fn gp_name(&self) -> String {
+        if self.name.ends_with("Grand Prix") {
+            self.name.clone()
+        } else {
+            format!("{} Grand Prix", self.name)
+        }
+    }
+
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    pub fn title(&self) -> String {
+        format!("{} / {}", self.gp_name(), self.location)
+    }
+
+    pub fn pp_race_status(&self, curr_dt: DateTime<Local>) -> &'static str {
+        if curr_dt > self.sessions.gp_start_dt() {
             "[x]"
         } else if (self.sessions.gp_start_dt() - curr_dt).num_days() < 7 {
             "[->"
         } else {
             "[ ]"
-        };
-        format!("{}  {:>2}. {}", is_past, round, race_name)
+        }
     }
 
     pub fn pp_race_schedule(&self, output: &mut String) -> Result<()> {
-        let race_name = format!("{} Grand Prix / {}", self.name, self.location);
-        let line_width = if race_name.len() < 38 {
-            38
-        } else {
-            race_name.len()
-        };
-        let border_width = line_width + 2;
-        // format GP title
-        writeln!(output, "+{}+", "-".repeat(border_width))?;
-        writeln!(output, "| {race_name:^line_width$} |")?;
-        writeln!(output, "+{}+", "-".repeat(border_width))?;
-
-        // format all GP sessions
-        self.sessions.pp_session(output, line_width)?;
+        self.pp_race_schedule_at(output, Local::now(), false)
+    }
 
-        // closing border
-        writeln!(output, "+{}+", "-".repeat(border_width))?;
+    fn pp_race_schedule_at(
+        &self,
+        output: &mut String,
+        curr_dt: DateTime<Local>,
+        with_seconds: bool,
+    ) -> Result<()> {
+        let mut table = Table::new()
+            .title(self.title())
+            .column("", Align::Left)
+            .column("", Align::Left)
+            .column("", Align::Left);
+        for (session_name, session_dt) in self.sessions.list() {
+            table.add_row(vec![
+                Cell::colored(
+                    session_status(session_dt, curr_dt),
+                    (curr_dt > session_dt).then_some(Color::GREY),
+                ),
+                session_name.into(),
+                session_dt.format(STR_FMT).into(),
+            ]);
+        }
 
         // time until next session
-        let until_next = self.sessions.pp_time_until_next_session();
+        let until_next = self
+            .sessions
+            .pp_time_until_next_session(curr_dt, with_seconds);
         if !until_next.is_empty() {
-            writeln!(output, "{}", until_next)?;
+            table.add_footer(until_next);
         }
-        Ok(())
+        table.render(output)
     }
+
     pub fn gp_start_dt(&self) -> DateTime<Local> {
         self.sessions.gp_start_dt()
     }
+
+    pub fn sessions(&self) -> [(&'static str, DateTime<Local>); 5] {
+        self.sessions.list()
+    }
+
+    pub fn is_sprint_weekend(&self) -> bool {
+        matches!(self.sessions, Sessions::Sprint(_))
+    }
+
+    // Sprint & race sessions yet to start, i.e. sessions which can still award points
+    pub fn pending_points_sessions(&self, curr_dt: DateTime<Local>) -> Vec<&'static str> {
+        self.sessions
+            .list()
+            .into_iter()
+            .filter(|(name, dt)| (*name == SPRINT || *name == RACE) && *dt > curr_dt)
+            .map(|(name, _)| name)
+            .collect()
+    }
+}
+
+// Redraws schedule of the upcoming Grand Prix every second until interrupted.
+// Once a race starts, the following Grand Prix is shown
+pub fn watch_next_race(races: &[GrandPrix]) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    loop {
+        let curr_dt = Local::now();
+        let Some(race) = races.iter().find(|race| curr_dt < race.gp_start_dt()) else {
+            eprintln!("No more Grand Prix races scheduled");
+            return Ok(());
+        };
+
+        // clearing screen & moving cursor to top left
+        let mut output = String::from("\x1b[2J\x1b[H");
+        race.pp_race_schedule_at(&mut output, curr_dt, true)?;
+        output.push_str("Press Ctrl+C to exit\n");
+        stdout.write_all(output.as_bytes())?;
+        stdout.flush()?;
+
+        // waking up on the next full second so countdown does not drift
+        let millis_to_next_second = 1000 - curr_dt.timestamp_subsec_millis().min(999);
+        std::thread::sleep(Duration::from_millis(millis_to_next_second as u64));
+    }
+}
+
+pub fn pp_race_list(races: &[GrandPrix], output: &mut String) -> Result<()> {
+    let curr_dt = Local::now();
+    let mut table = Table::new()
+        .column("", Align::Left)
+        .column("#", Align::Right)
+        .column("Grand Prix", Align::Left)
+        .column("Location", Align::Left);
+    for (idx, race) in races.iter().enumerate() {
+        let status = race.pp_race_status(curr_dt);
+        let color = match status {
+            "[x]" => Some(Color::GREY),
+            "[->" => Some(Color::GREEN),
+            _ => None,
+        };
+        table.add_row(vec![
+            Cell::colored(status, color),
+            (idx + 1).into(),
+            Cell::colored(race.gp_name(), color),
+            race.location.as_str().into(),
+        ]);
+    }
+    table.add_footer("[x] Completed || [-> This weekend || [ ] Pending");
+    table.render(output)
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -256,15 +281,15 @@ pub struct Schedule {
 impl DataFetcher for Schedule {
     type A = Vec<GrandPrix>;
 
-    fn cache_file_name() -> String {
-        format!("{}_schedule.json", *CURR_YEAR)
+    fn cache_file_name(year: i32) -> String {
+        format!("{}_schedule.json", year)
     }
 
-    fn resource_url() -> String {
+    fn resource_url(year: i32) -> String {
         println!("Fetching schedule");
         format!(
             "https://raw.githubusercontent.com/sportstimes/f1/main/_db/f1/{}.json",
-            *CURR_YEAR
+            year
         )
     }
 
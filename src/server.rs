@@ -0,0 +1,390 @@
+use chrono::{DateTime, Local, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+use crate::quali::CompletedQualifying;
+use crate::results::CompletedRace;
+use crate::schedule::{GrandPrix, Schedule};
+use crate::standings::driver_standings::DriverStandings;
+use crate::standings::team_standings::TeamStandings;
+use crate::utils::{flag_value, DataFetcher, PositionInfo};
+
+const DEFAULT_PORT: &str = "8080";
+const DEFAULT_REFRESH_MINS: &str = "30";
+const HTTP_DATE_FMT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+// Connections which do not send or accept a request in time are dropped, so
+// idle clients do not hold on to a thread
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+// Data along with the time its cache file was written, used as `Last-Modified`
+struct Source<T> {
+    data: T,
+    modified: DateTime<Utc>,
+}
+
+impl<T> Source<T> {
+    fn load<F: DataFetcher<A = T>>() -> Result<Self>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        let data = F::get_data()?;
+        let modified = F::cache_modified().unwrap_or_else(Utc::now);
+        Ok(Self { data, modified })
+    }
+}
+
+struct ServerData {
+    schedule: Source<Vec<GrandPrix>>,
+    drivers: Source<Vec<PositionInfo>>,
+    teams: Source<Vec<PositionInfo>>,
+    results: Source<Vec<CompletedRace>>,
+    quali: Source<Vec<CompletedQualifying>>,
+}
+
+impl ServerData {
+    fn load() -> Result<Self> {
+        Ok(Self {
+            schedule: Source::load::<Schedule>()?,
+            drivers: Source::load::<DriverStandings>()?,
+            teams: Source::load::<TeamStandings>()?,
+            results: Source::load::<CompletedRace>()?,
+            quali: Source::load::<CompletedQualifying>()?,
+        })
+    }
+
+    fn pull_and_load() -> Result<Self> {
+        Schedule::pull()?;
+        TeamStandings::pull()?;
+        DriverStandings::pull()?;
+        CompletedRace::pull()?;
+        CompletedQualifying::pull()?;
+        Self::load()
+    }
+
+    // Returns serialized body for `path` & when its data was last modified,
+    // `None` when resource does not exist
+    fn route(&self, path: &str) -> Result<Option<(String, DateTime<Utc>)>> {
+        let segments = path
+            .trim_matches('/')
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+        let (schedule, results, quali) = (&self.schedule, &self.results, &self.quali);
+        let body = match segments.as_slice() {
+            ["schedule"] => (to_json(&schedule.data)?, schedule.modified),
+            ["next"] => {
+                let curr_dt = Local::now();
+                match schedule.data.iter().find(|r| curr_dt < r.gp_start_dt()) {
+                    Some(race) => (to_json(race)?, schedule.modified),
+                    None => return Ok(None),
+                }
+            }
+            ["standings", "drivers"] => (to_json(&self.drivers.data)?, self.drivers.modified),
+            ["standings", "teams"] => (to_json(&self.teams.data)?, self.teams.modified),
+            ["results"] => (to_json(&results.data)?, results.modified),
+            ["results", round] => match results.data.iter().find(|r| r.round.to_string() == *round)
+            {
+                Some(race) => (to_json(race)?, results.modified),
+                None => return Ok(None),
+            },
+            ["quali"] => (to_json(&quali.data)?, quali.modified),
+            ["quali", round] => match quali.data.iter().find(|q| q.round.to_string() == *round) {
+                Some(round_quali) => (to_json(round_quali)?, quali.modified),
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        Ok(Some(body))
+    }
+}
+
+fn to_json<T: Serialize + ?Sized>(data: &T) -> Result<String> {
+    Ok(serde_json::to_string(data)?)
+}
+
+fn etag(body: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    format!("\"{:x}\"", hasher.finish())
+}
+
+struct Request {
+    method: String,
+    path: String,
+    if_none_match: Option<String>,
+}
+
+fn read_request(stream: &TcpStream) -> Result<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().ok_or(Error::HttpRequest)?.to_owned();
+    let path = parts.next().ok_or(Error::HttpRequest)?.to_owned();
+
+    let mut if_none_match = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("if-none-match") {
+                if_none_match = Some(value.trim().to_owned());
+            }
+        }
+    }
+
+    // query string is not used by any endpoint
+    let path = path.split('?').next().unwrap_or_default().to_owned();
+    Ok(Request {
+        method,
+        path,
+        if_none_match,
+    })
+}
+
+fn write_response(
+    stream: &mut TcpStream,
+    status: &str,
+    headers: &[(&str, String)],
+    body: &str,
+    send_body: bool,
+) -> Result<()> {
+    let mut response = format!("HTTP/1.1 {status}\r\n");
+    for (name, value) in headers {
+        response.push_str(&format!("{name}: {value}\r\n"));
+    }
+    response.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    response.push_str("Connection: close\r\n\r\n");
+    if send_body {
+        response.push_str(body);
+    }
+    stream.write_all(response.as_bytes())?;
+    Ok(())
+}
+
+fn handle_connection(mut stream: TcpStream, data: &RwLock<ServerData>, max_age: u64) -> Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(REQUEST_TIMEOUT_SECS)))?;
+    stream.set_write_timeout(Some(Duration::from_secs(REQUEST_TIMEOUT_SECS)))?;
+    let request = read_request(&stream)?;
+    let send_body = request.method != "HEAD";
+    if request.method != "GET" && request.method != "HEAD" {
+        let headers = [("Allow", "GET, HEAD".to_owned())];
+        return write_response(
+            &mut stream,
+            "405 Method Not Allowed",
+            &headers,
+            "",
+            send_body,
+        );
+    }
+
+    let routed = {
+        // a panicked writer leaves previous data intact, so serving it is fine
+        let data = data.read().unwrap_or_else(|err| err.into_inner());
+        data.route(&request.path)
+    };
+    let routed = match routed {
+        Ok(routed) => routed,
+        Err(err) => {
+            let body = r#"{"error":"internal server error"}"#;
+            let headers = [("Content-Type", "application/json".to_owned())];
+            write_response(
+                &mut stream,
+                "500 Internal Server Error",
+                &headers,
+                body,
+                send_body,
+            )?;
+            return Err(err);
+        }
+    };
+    let Some((body, modified)) = routed else {
+        let body = r#"{"error":"not found"}"#;
+        let headers = [("Content-Type", "application/json".to_owned())];
+        return write_response(&mut stream, "404 Not Found", &headers, body, send_body);
+    };
+
+    let etag = etag(&body);
+    let headers = [
+        ("Content-Type", "application/json".to_owned()),
+        ("Cache-Control", format!("public, max-age={max_age}")),
+        ("Last-Modified", modified.format(HTTP_DATE_FMT).to_string()),
+        ("ETag", etag.clone()),
+    ];
+    if request.if_none_match.as_deref() == Some(etag.as_str()) {
+        return write_response(&mut stream, "304 Not Modified", &headers, "", false);
+    }
+    write_response(&mut stream, "200 OK", &headers, &body, send_body)
+}
+
+// Serves cached data as read only JSON endpoints. Data is pulled again every
+// `--refresh` minutes in a background thread
+pub fn run_server(args: &[String]) -> Result<()> {
+    let port = flag_value(args, "--port")
+        .unwrap_or(DEFAULT_PORT)
+        .parse::<u16>()?;
+    let refresh_mins = flag_value(args, "--refresh")
+        .unwrap_or(DEFAULT_REFRESH_MINS)
+        .parse::<u64>()?;
+    // pulling more often than once a minute only loads the source site, also
+    // used as `max-age` so clients don't ask for data before it can change
+    let refresh_secs = (refresh_mins * 60).max(60);
+
+    let data = Arc::new(RwLock::new(ServerData::load()?));
+
+    let refresh_data = data.clone();
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_secs(refresh_secs));
+        match ServerData::pull_and_load() {
+            Ok(new_data) => {
+                if let Ok(mut guarded_data) = refresh_data.write() {
+                    *guarded_data = new_data;
+                }
+            }
+            Err(err) => eprintln!("Background refresh failed: {err}"),
+        }
+    });
+
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    println!("Serving on http://127.0.0.1:{port}");
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("Connection failed: {err}");
+                continue;
+            }
+        };
+        let data = data.clone();
+        std::thread::spawn(move || {
+            if let Err(err) = handle_connection(stream, &data, refresh_secs) {
+                eprintln!("Request failed: {err}");
+            }
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn source<T: DeserializeOwned>(data: serde_json::Value) -> Source<T> {
+        Source {
+            data: serde_json::from_value(data).unwrap(),
+            modified: Utc.with_ymd_and_hms(2026, 3, 8, 6, 0, 0).unwrap(),
+        }
+    }
+
+    fn server_data() -> ServerData {
+        let driver = json!({"position": 1, "name": "Max Verstappen", "points": 25,
+            "team": "Red Bull Racing", "status": null, "reason": null,
+            "nationality": "NED", "number": 1});
+        let quali_driver = json!({"position": 1, "name": "Max Verstappen",
+            "team": "Red Bull Racing", "q1": null, "q2": null, "q3": null});
+        ServerData {
+            schedule: source(json!([])),
+            drivers: source(json!([driver])),
+            teams: source(json!([])),
+            results: source(json!([{"round": 1, "gp_name": "Bahrain Grand Prix",
+                "results": [driver]}])),
+            quali: source(json!([{"round": 1, "gp_name": "Bahrain Grand Prix",
+                "results": [quali_driver]}])),
+        }
+    }
+
+    fn body(data: &ServerData, path: &str) -> Option<serde_json::Value> {
+        data.route(path)
+            .unwrap()
+            .map(|(body, _)| serde_json::from_str(&body).unwrap())
+    }
+
+    #[test]
+    fn routes_known_paths() {
+        let data = server_data();
+        assert_eq!(body(&data, "/schedule"), Some(json!([])));
+        assert_eq!(
+            body(&data, "/standings/drivers").unwrap()[0]["name"],
+            "Max Verstappen"
+        );
+        assert_eq!(body(&data, "/standings/teams"), Some(json!([])));
+        assert_eq!(body(&data, "/results").unwrap()[0]["round"], 1);
+        // trailing & repeated slashes are ignored
+        assert_eq!(
+            body(&data, "/results//1/").unwrap()["gp_name"],
+            "Bahrain Grand Prix"
+        );
+        assert_eq!(
+            body(&data, "/quali/1").unwrap()["results"][0]["position"],
+            1
+        );
+        let (_, modified) = data.route("/results/1").unwrap().unwrap();
+        assert_eq!(modified, data.results.modified);
+    }
+
+    #[test]
+    fn unknown_paths_and_rounds_are_not_found() {
+        let data = server_data();
+        for path in [
+            "/",
+            "/drivers",
+            "/standings",
+            "/results/2",
+            "/quali/x",
+            "/results/1/2",
+        ] {
+            assert!(body(&data, path).is_none(), "{path}");
+        }
+        // no upcoming Grand Prix in an empty schedule
+        assert!(body(&data, "/next").is_none());
+    }
+
+    fn request(data: &RwLock<ServerData>, request: &str) -> String {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        client.write_all(request.as_bytes()).unwrap();
+        let (stream, _) = listener.accept().unwrap();
+        handle_connection(stream, data, 60).unwrap();
+        let mut response = String::new();
+        std::io::Read::read_to_string(&mut client, &mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn serves_status_and_headers() {
+        let data = RwLock::new(server_data());
+        let ok = request(&data, "GET /results/1 HTTP/1.1\r\n\r\n");
+        assert!(ok.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(ok.contains("Cache-Control: public, max-age=60\r\n"));
+        assert!(ok.ends_with("}"));
+
+        let etag = ok
+            .lines()
+            .find_map(|line| line.strip_prefix("ETag: "))
+            .unwrap();
+        let cached = request(
+            &data,
+            &format!("GET /results/1 HTTP/1.1\r\nIf-None-Match: {etag}\r\n\r\n"),
+        );
+        assert!(cached.starts_with("HTTP/1.1 304 Not Modified\r\n"));
+
+        let head = request(&data, "HEAD /results/1 HTTP/1.1\r\n\r\n");
+        assert!(head.starts_with("HTTP/1.1 200 OK\r\n") && head.ends_with("\r\n\r\n"));
+
+        let missing = request(&data, "GET /results/9 HTTP/1.1\r\n\r\n");
+        assert!(missing.starts_with("HTTP/1.1 404 Not Found\r\n"));
+        let post = request(&data, "POST /results HTTP/1.1\r\n\r\n");
+        assert!(post.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+    }
+}
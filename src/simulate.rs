@@ -0,0 +1,303 @@
+use chrono::Local;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::{Error, Result};
+use crate::points::{PointsSystem, SPRINT_POINTS};
+use crate::results::CompletedRace;
+use crate::schedule::{GrandPrix, SPRINT};
+use crate::table::{team_color, Align, Cell, Table};
+use crate::utils::{flag_value, same_team, PositionInfo};
+
+const DEFAULT_RUNS: usize = 10000;
+
+// xorshift64*, good enough for simulations & reproducible across platforms
+struct Rng(u64);
+
+impl Rng {
+    // Seed is scrambled with splitmix64, so nearby seeds give unrelated streams
+    fn new(seed: u64) -> Self {
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        // state must never be zero, which splitmix64 gives for a single seed
+        Self(if z == 0 { 0x9E37_79B9_7F4A_7C15 } else { z })
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+struct Contender<'a> {
+    name: &'a str,
+    points: usize,
+    // past race positions, `0` for not classified. Empty if not on the current grid
+    finishes: Vec<usize>,
+}
+
+struct Projection {
+    title_chances: f64,
+    final_points: Vec<usize>,
+}
+
+impl Projection {
+    fn new(runs: usize) -> Self {
+        Self {
+            title_chances: 0.0,
+            final_points: Vec::with_capacity(runs),
+        }
+    }
+
+    fn mean(&self) -> f64 {
+        self.final_points.iter().sum::<usize>() as f64 / self.final_points.len() as f64
+    }
+
+    // points range covering 90% of the runs
+    fn pp_range(&mut self) -> String {
+        self.final_points.sort_unstable();
+        let len = self.final_points.len();
+        let low = self.final_points[len * 5 / 100];
+        let high = self.final_points[(len * 95 / 100).min(len - 1)];
+        format!("{low} - {high}")
+    }
+}
+
+// Orders the current grid by sampling a past finish for every driver, ties are
+// broken randomly and drivers sampled as not classified are placed last
+fn simulate_session(contenders: &[Contender], rng: &mut Rng) -> Vec<usize> {
+    let last = contenders.len() + 1;
+    let mut sampled = contenders
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| !c.finishes.is_empty())
+        .map(|(idx, c)| {
+            let finish = match c.finishes[rng.below(c.finishes.len())] {
+                0 => last,
+                position => position,
+            };
+            (idx, finish as f64 + rng.next_f64())
+        })
+        .collect::<Vec<_>>();
+    sampled.sort_by(|a, b| a.1.total_cmp(&b.1));
+    sampled.into_iter().map(|(idx, _)| idx).collect()
+}
+
+// Adds title chances, split equally between contenders tied on points
+fn record_run(projections: &mut [Projection], final_points: &[usize]) {
+    let best = final_points.iter().max().copied().unwrap_or(0);
+    let leaders = final_points.iter().filter(|p| **p == best).count();
+    for (projection, points) in projections.iter_mut().zip(final_points) {
+        projection.final_points.push(*points);
+        if *points == best {
+            projection.title_chances += 1.0 / leaders as f64;
+        }
+    }
+}
+
+fn projection_table(
+    title: String,
+    is_team: bool,
+    standings: &[PositionInfo],
+    mut projections: Vec<Projection>,
+    runs: usize,
+) -> Table {
+    let mut table = Table::new()
+        .title(title)
+        .column(if is_team { "Team" } else { "Driver" }, Align::Left)
+        .column("Points", Align::Right)
+        .column("Title %", Align::Right)
+        .column("Expected", Align::Right)
+        .column("Range (90%)", Align::Right);
+
+    let mut order = (0..standings.len()).collect::<Vec<_>>();
+    order.sort_by_key(|idx| Reverse((projections[*idx].mean() * 10.0) as usize));
+    for idx in order {
+        let entry = &standings[idx];
+        let projection = &mut projections[idx];
+        let color = if is_team {
+            team_color(&entry.name)
+        } else {
+            None
+        };
+        table.add_row(vec![
+            Cell::colored(&entry.name, color),
+            entry.points.into(),
+            format!("{:.1}", projection.title_chances * 100.0 / runs as f64).into(),
+            format!("{:.0}", projection.mean()).into(),
+            projection.pp_range().into(),
+        ]);
+    }
+    table
+}
+
+pub fn run_simulation(
+    args: &[String],
+    schedule: &[GrandPrix],
+    driver_standings: &[PositionInfo],
+    team_standings: &[PositionInfo],
+    races: &[CompletedRace],
+    output: &mut String,
+) -> Result<()> {
+    let runs = match flag_value(args, "--runs") {
+        Some(runs) => runs.parse::<usize>()?,
+        None => DEFAULT_RUNS,
+    };
+    if runs == 0 {
+        return Err(Error::InvalidArgs);
+    }
+    let seed = match flag_value(args, "--seed") {
+        Some(seed) => seed.parse::<u64>()?,
+        None => SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default(),
+    };
+
+    let curr_dt = Local::now();
+    let sessions = schedule
+        .iter()
+        .flat_map(|gp| gp.pending_points_sessions(curr_dt))
+        .collect::<Vec<_>>();
+    if sessions.is_empty() {
+        eprintln!("No races remaining this season, run `f1gp drivers` for final standings");
+        return Ok(());
+    }
+    let sprints = sessions.iter().filter(|s| **s == SPRINT).count();
+    let race_points = PointsSystem::from_arg("current")?;
+
+    // only drivers who took part in the latest race are simulated, the
+    // rest keep their current points
+    let grid = races
+        .last()
+        .map(|r| r.results.as_slice())
+        .unwrap_or_default();
+    let contenders = driver_standings
+        .iter()
+        .map(|driver| Contender {
+            name: &driver.name,
+            points: driver.points,
+            finishes: if grid.iter().any(|d| d.name == driver.name) {
+                races
+                    .iter()
+                    .flat_map(|r| r.results.iter())
+                    .filter(|d| d.name == driver.name)
+                    .map(|d| d.position)
+                    .collect()
+            } else {
+                Vec::new()
+            },
+        })
+        .collect::<Vec<_>>();
+
+    // team standings entry of every driver on the current grid
+    let driver_team = contenders
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, c)| {
+            let team = grid.iter().find(|d| d.name == c.name)?.team.as_deref()?;
+            // exact match first, `McLaren Mercedes` should not match `Mercedes`
+            let team_idx = team_standings
+                .iter()
+                .position(|t| t.name == team)
+                .or_else(|| team_standings.iter().position(|t| same_team(&t.name, team)))?;
+            Some((idx, team_idx))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut rng = Rng::new(seed);
+    let mut driver_projections = (0..contenders.len())
+        .map(|_| Projection::new(runs))
+        .collect::<Vec<_>>();
+    let mut team_projections = (0..team_standings.len())
+        .map(|_| Projection::new(runs))
+        .collect::<Vec<_>>();
+    for _ in 0..runs {
+        let mut driver_points = contenders.iter().map(|c| c.points).collect::<Vec<_>>();
+        for session in &sessions {
+            let order = simulate_session(&contenders, &mut rng);
+            for (position, idx) in order.into_iter().enumerate() {
+                driver_points[idx] += if *session == SPRINT {
+                    SPRINT_POINTS.get(position).copied().unwrap_or(0)
+                } else {
+                    race_points.points_for(position + 1, false)
+                };
+            }
+        }
+
+        let mut team_points = team_standings.iter().map(|t| t.points).collect::<Vec<_>>();
+        for (driver_idx, team_idx) in &driver_team {
+            team_points[*team_idx] += driver_points[*driver_idx] - contenders[*driver_idx].points;
+        }
+        record_run(&mut driver_projections, &driver_points);
+        record_run(&mut team_projections, &team_points);
+    }
+
+    let title = format!(
+        "SEASON SIMULATION - {} races & {} sprints remaining",
+        sessions.len() - sprints,
+        sprints
+    );
+    let mut table = projection_table(title, false, driver_standings, driver_projections, runs);
+    table.add_footer(format!(
+        "{runs} runs with seed {seed}, finishes are sampled from this season's race results"
+    ));
+    table.render(output)?;
+
+    output.push('\n');
+    projection_table(
+        "CONSTRUCTORS".to_owned(),
+        true,
+        team_standings,
+        team_projections,
+        runs,
+    )
+    .render(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rng_is_reproducible() {
+        let (mut first, mut second) = (Rng::new(42), Rng::new(42));
+        for _ in 0..100 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+        assert_ne!(Rng::new(42).next_u64(), Rng::new(43).next_u64());
+    }
+
+    #[test]
+    fn rng_never_gets_stuck_at_zero() {
+        // used to XOR to a zero state, giving nothing but zeros
+        for seed in [0, 0x9E37_79B9_7F4A_7C15, u64::MAX] {
+            let mut rng = Rng::new(seed);
+            assert_ne!(rng.0, 0);
+            assert!((0..10).any(|_| rng.next_u64() != 0));
+        }
+    }
+
+    #[test]
+    fn rng_ranges() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!(rng.below(20) < 20);
+            let value = rng.next_f64();
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::error::Result;
+use crate::results::fetch_parse_individual_race;
+use crate::schedule::Schedule;
+use crate::utils::{fetch_round_pages, DataFetcher, PositionInfo, F1_BASE_URL};
+
+// Sprint results page has the same layout as the race result page
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CompletedSprint {
+    pub round: usize,
+    pub gp_name: String,
+    pub results: Vec<PositionInfo>,
+}
+
+impl DataFetcher for CompletedSprint {
+    type A = Vec<CompletedSprint>;
+
+    fn cache_file_name(year: i32) -> String {
+        format!("{}_sprint_results.json", year)
+    }
+
+    fn resource_url(year: i32) -> String {
+        println!("Fetching sprint results for all completed Grand Prix");
+        format!("{}/en/results/{}/races", F1_BASE_URL, year)
+    }
+
+    // Rounds without a sprint have no sprint results page, so they are skipped
+    // along with cached rounds. Sprint weekends are taken from the current
+    // season's schedule
+    fn process_data(raw_data: String, file_path: &Path) -> Result<Self::A> {
+        let mut all_sprints: Vec<CompletedSprint> = if file_path.exists() {
+            Self::read_from_cache(file_path).unwrap_or(Vec::new())
+        } else {
+            Vec::new()
+        };
+        let mut rounds_skipped = all_sprints.iter().map(|r| r.round).collect::<Vec<usize>>();
+        for (idx, gp) in Schedule::get_data()?.iter().enumerate() {
+            if !gp.is_sprint_weekend() {
+                rounds_skipped.push(idx + 1);
+            }
+        }
+        let fetched = fetch_round_pages(
+            raw_data,
+            rounds_skipped,
+            "sprint-results",
+            fetch_parse_individual_race,
+        )?;
+        all_sprints.extend(fetched.into_iter().map(|page| CompletedSprint {
+            round: page.round,
+            gp_name: page.gp_name,
+            results: page.data,
+        }));
+        all_sprints.sort_by_key(|r| r.round);
+        Ok(all_sprints)
+    }
+}
@@ -1,15 +1,10 @@
 use std::path::Path;
-use std::sync::LazyLock;
 
 use scraper::{selectable::Selectable, ElementRef};
 
 use super::{parse_standings_html_table, STANDINGS_BASE_URL};
 use crate::error::{Error, Result};
-use crate::utils::{DataFetcher, PositionInfo};
-use crate::CURR_YEAR;
-
-static DRIVER_STANDINGS_FETCH_URL: LazyLock<String> =
-    LazyLock::new(|| format!("{}/drivers.html", *CURR_YEAR));
+use crate::utils::{cell_text, DataFetcher, PositionInfo};
 
 fn parse_driver_table_row(element: ElementRef) -> Result<PositionInfo> {
     // NOTE: Parsing based on current website layout, may need to modify parsing
@@ -24,7 +19,7 @@ fn parse_driver_table_row(element: ElementRef) -> Result<PositionInfo> {
         .text()
         .collect::<Vec<_>>();
     let position = position
-        .get(0)
+        .first()
         .ok_or_else(|| Error::ParseDriverInfo(1.1))?
         .parse::<usize>()?;
 
@@ -49,19 +44,19 @@ fn parse_driver_table_row(element: ElementRef) -> Result<PositionInfo> {
         .ok_or(Error::ParseDriverInfo(3.0))?
         .text()
         .collect::<Vec<_>>();
-    let first = first.get(0).ok_or_else(|| Error::ParseDriverInfo(3.1))?;
+    let first = first.first().ok_or_else(|| Error::ParseDriverInfo(3.1))?;
 
     let second = span_iter
         .next()
         .ok_or(Error::ParseDriverInfo(4.0))?
         .text()
         .collect::<Vec<_>>();
-    let second = second.get(0).ok_or_else(|| Error::ParseDriverInfo(3.2))?;
+    let second = second.first().ok_or_else(|| Error::ParseDriverInfo(3.2))?;
     let name = format!("{} {}", first, second);
 
-    //skipping nationaliy & team
-    iter.next();
-    iter.next();
+    // nationality & team
+    let nationality = iter.next().map(cell_text).filter(|n| !n.is_empty());
+    let team = iter.next().map(cell_text).filter(|t| !t.is_empty());
 
     // points
     let points = iter
@@ -75,6 +70,11 @@ fn parse_driver_table_row(element: ElementRef) -> Result<PositionInfo> {
         position,
         name,
         points,
+        team,
+        status: None,
+        reason: None,
+        nationality,
+        number: None,
     })
 }
 
@@ -83,13 +83,13 @@ pub struct DriverStandings;
 impl DataFetcher for DriverStandings {
     type A = Vec<PositionInfo>;
 
-    fn cache_file_name() -> String {
-        format!("{}_driver_standings.json", *CURR_YEAR)
+    fn cache_file_name(year: i32) -> String {
+        format!("{}_driver_standings.json", year)
     }
 
-    fn resource_url() -> String {
+    fn resource_url(year: i32) -> String {
         println!("Fetching Driver standings");
-        format!("{}/{}", STANDINGS_BASE_URL, *DRIVER_STANDINGS_FETCH_URL)
+        format!("{}/{}/drivers.html", STANDINGS_BASE_URL, year)
     }
 
     fn process_data(raw_data: String, _file_path: &Path) -> Result<Self::A> {
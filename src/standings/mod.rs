@@ -1,10 +1,16 @@
 pub mod driver_standings;
 pub mod team_standings;
 
+use std::cmp::Reverse;
+
 use scraper::ElementRef;
 
 use crate::error::{Error, Result};
-use crate::utils::{PositionInfo, F1_TABLE_SELECTOR};
+use crate::quali::CompletedQualifying;
+use crate::results::CompletedRace;
+use crate::sprint::CompletedSprint;
+use crate::table::{team_color, Align, Cell, Color, Table};
+use crate::utils::{same_team, PositionInfo, F1_TABLE_SELECTOR};
 
 pub const STANDINGS_BASE_URL: &str = "https://www.formula1.com/en/results.html";
 
@@ -26,3 +32,292 @@ fn parse_standings_html_table(
     }
     Ok(standings)
 }
+
+#[derive(Clone, Copy)]
+pub enum StandingsSort {
+    Position,
+    Wins,
+    Podiums,
+    Poles,
+    Name,
+}
+
+impl StandingsSort {
+    pub fn from_arg(arg: &str) -> Result<Self> {
+        let sort = match arg.to_lowercase().as_str() {
+            "pos" | "position" | "points" => Self::Position,
+            "wins" => Self::Wins,
+            "podiums" => Self::Podiums,
+            "poles" => Self::Poles,
+            "name" => Self::Name,
+            _ => return Err(Error::InvalidArgs),
+        };
+        Ok(sort)
+    }
+}
+
+#[derive(Default)]
+struct Stats {
+    wins: usize,
+    podiums: usize,
+    poles: usize,
+}
+
+// Wins & podiums from race results, poles from qualifying. Sprints are not counted
+fn standings_stats(
+    entry: &PositionInfo,
+    is_team: bool,
+    races: &[CompletedRace],
+    qualifying: &[CompletedQualifying],
+) -> Stats {
+    let is_entry = |name: &str, team: Option<&str>| {
+        if is_team {
+            team.is_some_and(|t| same_team(t, &entry.name))
+        } else {
+            name == entry.name
+        }
+    };
+    let mut stats = Stats::default();
+    for result in races.iter().flat_map(|r| r.results.iter()) {
+        if (1..=3).contains(&result.position) && is_entry(&result.name, result.team.as_deref()) {
+            stats.podiums += 1;
+            if result.position == 1 {
+                stats.wins += 1;
+            }
+        }
+    }
+    stats.poles = qualifying
+        .iter()
+        .filter_map(|q| q.results.iter().find(|d| d.position == 1))
+        .filter(|d| is_entry(&d.name, d.team.as_deref()))
+        .count();
+    stats
+}
+
+// `is_team` switches to team standings. Entries are colored by their team color,
+// driver rows only carry team information when cached by recent versions
+pub fn pp_standings(
+    title: &str,
+    standings: &[PositionInfo],
+    is_team: bool,
+    races: &[CompletedRace],
+    qualifying: &[CompletedQualifying],
+    sort: StandingsSort,
+    output: &mut String,
+) -> Result<()> {
+    let mut table = Table::new()
+        .title(title)
+        .column("Pos", Align::Right)
+        .column(if is_team { "Team" } else { "Driver" }, Align::Left);
+    if !is_team {
+        table = table.column("Nat", Align::Left).column("Team", Align::Left);
+    }
+    table = table
+        .column("Points", Align::Right)
+        .column("Wins", Align::Right)
+        .column("Podiums", Align::Right)
+        .column("Poles", Align::Right)
+        .column("Gap", Align::Right)
+        .column("Interval", Align::Right);
+
+    let leader_points = standings.first().map(|e| e.points).unwrap_or_default();
+    // gaps are always to the entry ahead in the championship, whatever the sort order
+    let mut rows = standings
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| {
+            let ahead = idx.checked_sub(1).map(|i| standings[i].points);
+            let stats = standings_stats(entry, is_team, races, qualifying);
+            (entry, stats, ahead)
+        })
+        .collect::<Vec<_>>();
+    match sort {
+        StandingsSort::Position => {}
+        StandingsSort::Wins => rows.sort_by_key(|(_, stats, _)| Reverse(stats.wins)),
+        StandingsSort::Podiums => rows.sort_by_key(|(_, stats, _)| Reverse(stats.podiums)),
+        StandingsSort::Poles => rows.sort_by_key(|(_, stats, _)| Reverse(stats.poles)),
+        StandingsSort::Name => rows.sort_by(|(a, _, _), (b, _, _)| a.name.cmp(&b.name)),
+    }
+
+    for (entry, stats, ahead) in rows {
+        let color = if is_team {
+            team_color(&entry.name)
+        } else {
+            entry.team.as_deref().and_then(team_color)
+        };
+        let gap = |points: usize| match points.saturating_sub(entry.points) {
+            0 => "0".to_owned(),
+            gap => format!("-{gap}"),
+        };
+        let mut row = vec![entry.position.into(), Cell::colored(&entry.name, color)];
+        if !is_team {
+            let team = entry.team.as_deref().unwrap_or("-");
+            row.push(entry.nationality.as_deref().unwrap_or("-").into());
+            row.push(Cell::colored(team, color));
+        }
+        row.extend([
+            entry.points.into(),
+            stats.wins.into(),
+            stats.podiums.into(),
+            stats.poles.into(),
+            // leader is the only entry without one ahead
+            ahead
+                .map(|_| gap(leader_points))
+                .unwrap_or("-".to_owned())
+                .into(),
+            ahead.map(gap).unwrap_or("-".to_owned()).into(),
+        ]);
+        table.add_row(row);
+    }
+    table.add_footer("Wins, podiums & poles are Grand Prix only, sprints are not counted");
+    table.render(output)
+}
+
+pub fn pp_position_change(change: i64) -> Cell {
+    match change {
+        change if change > 0 => Cell::colored(format!("▲{change}"), Some(Color::GREEN)),
+        change if change < 0 => Cell::colored(format!("▼{}", -change), Some(Color::RED)),
+        _ => Cell::colored("=", Some(Color::GREY)),
+    }
+}
+
+pub struct Movement<'a> {
+    pub entry: &'a PositionInfo,
+    pub position: usize,
+    pub points: usize,
+    // positions gained in `round`, negative when positions were lost
+    pub change: i64,
+    pub gained: usize,
+}
+
+// Standings right after `round` are derived by taking away points scored in the
+// races & sprints after it, so no separate snapshots need to be cached
+pub fn standings_movement<'a>(
+    standings: &'a [PositionInfo],
+    races: &[CompletedRace],
+    sprints: &[CompletedSprint],
+    round: usize,
+) -> Vec<Movement<'a>> {
+    let points_after = |entry: &PositionInfo, round: usize| {
+        let later_results = races
+            .iter()
+            .filter(|r| r.round > round)
+            .map(|r| &r.results)
+            .chain(
+                sprints
+                    .iter()
+                    .filter(|s| s.round > round)
+                    .map(|s| &s.results),
+            );
+        let later_points = later_results
+            .flat_map(|results| results.iter().filter(|d| d.name == entry.name))
+            .map(|d| d.points)
+            .sum::<usize>();
+        entry.points.saturating_sub(later_points)
+    };
+    // ties keep the current order as countback is not possible without all results
+    let ranked = |round: usize| {
+        let mut ranked = standings
+            .iter()
+            .map(|entry| (entry, points_after(entry, round)))
+            .collect::<Vec<_>>();
+        ranked.sort_by_key(|(entry, points)| (Reverse(*points), entry.position));
+        ranked
+    };
+
+    let before = ranked(round.saturating_sub(1));
+    ranked(round)
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (entry, points))| {
+            let (previous_pos, previous_points) = before
+                .iter()
+                .enumerate()
+                .find(|(_, (e, _))| e.name == entry.name)
+                .map(|(idx, (_, points))| (idx + 1, *points))
+                .unwrap_or((idx + 1, points));
+            Movement {
+                entry,
+                position: idx + 1,
+                points,
+                change: previous_pos as i64 - (idx + 1) as i64,
+                gained: points - previous_points,
+            }
+        })
+        .collect()
+}
+
+pub fn pp_standings_diff(
+    standings: &[PositionInfo],
+    races: &[CompletedRace],
+    sprints: &[CompletedSprint],
+    race: &CompletedRace,
+    output: &mut String,
+) -> Result<()> {
+    let mut table = Table::new()
+        .title(format!(
+            "DRIVER STANDINGS - since round {} {}",
+            race.round, race.gp_name
+        ))
+        .column("Pos", Align::Right)
+        .column("+/-", Align::Right)
+        .column("Driver", Align::Left)
+        .column("Points", Align::Right)
+        .column("Gained", Align::Right);
+    for movement in standings_movement(standings, races, sprints, race.round) {
+        let color = movement.entry.team.as_deref().and_then(team_color);
+        table.add_row(vec![
+            movement.position.into(),
+            pp_position_change(movement.change),
+            Cell::colored(&movement.entry.name, color),
+            movement.points.into(),
+            format!("+{}", movement.gained).into(),
+        ]);
+    }
+    table.render(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(position: usize, name: &str, points: usize) -> PositionInfo {
+        PositionInfo {
+            position,
+            name: name.to_owned(),
+            points,
+            team: None,
+            status: None,
+            reason: None,
+            nationality: None,
+            number: None,
+        }
+    }
+
+    #[test]
+    fn movement_takes_sprint_points_away() {
+        let standings = [entry(1, "Lando Norris", 40), entry(2, "Max Verstappen", 38)];
+        let races = [
+            CompletedRace {
+                round: 1,
+                gp_name: "Bahrain".to_owned(),
+                results: vec![entry(1, "Max Verstappen", 25), entry(2, "Lando Norris", 18)],
+            },
+            CompletedRace {
+                round: 2,
+                gp_name: "Chinese".to_owned(),
+                results: vec![entry(1, "Lando Norris", 15), entry(2, "Max Verstappen", 6)],
+            },
+        ];
+        let sprints = [CompletedSprint {
+            round: 2,
+            gp_name: "Chinese".to_owned(),
+            results: vec![entry(1, "Lando Norris", 7), entry(2, "Max Verstappen", 7)],
+        }];
+
+        let movement = standings_movement(&standings, &races, &sprints, 2);
+        assert_eq!(movement[0].entry.name, "Lando Norris");
+        assert_eq!((movement[0].change, movement[0].gained), (1, 22));
+        assert_eq!((movement[1].change, movement[1].gained), (-1, 13));
+    }
+}
@@ -1,15 +1,10 @@
 use std::path::Path;
-use std::sync::LazyLock;
 
 use scraper::ElementRef;
 
 use super::{parse_standings_html_table, STANDINGS_BASE_URL};
 use crate::error::{Error, Result};
 use crate::utils::{DataFetcher, PositionInfo};
-use crate::CURR_YEAR;
-
-static TEAM_STANDINGS_FETCH_URL: LazyLock<String> =
-    LazyLock::new(|| format!("{}/team.html", *CURR_YEAR));
 
 fn parse_team_table_row(element: ElementRef) -> Result<PositionInfo> {
     // NOTE: Parsing based on current website layout, may need to modify parsing
@@ -43,6 +38,11 @@ fn parse_team_table_row(element: ElementRef) -> Result<PositionInfo> {
         position,
         name,
         points,
+        team: None,
+        status: None,
+        reason: None,
+        nationality: None,
+        number: None,
     })
 }
 
@@ -51,13 +51,13 @@ pub struct TeamStandings;
 impl DataFetcher for TeamStandings {
     type A = Vec<PositionInfo>;
 
-    fn cache_file_name() -> String {
-        format!("{}_team_standings.json", *CURR_YEAR)
+    fn cache_file_name(year: i32) -> String {
+        format!("{}_team_standings.json", year)
     }
 
-    fn resource_url() -> String {
+    fn resource_url(year: i32) -> String {
         println!("Fetching Team standings");
-        format!("{}/{}", STANDINGS_BASE_URL, *TEAM_STANDINGS_FETCH_URL)
+        format!("{}/{}/team.html", STANDINGS_BASE_URL, year)
     }
 
     fn process_data(raw_data: String, _file_path: &Path) -> Result<Self::A> {
@@ -0,0 +1,461 @@
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::io::IsTerminal;
+use std::sync::LazyLock;
+
+use terminal_size::{terminal_size, Width};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::error::Result;
+
+// Columns are never shrunk below this width when fitting to terminal
+const MIN_COLUMN_WIDTH: usize = 4;
+const ELLIPSIS: char = '…';
+
+// Colors are only written when stdout is a terminal and `NO_COLOR` is not set.
+// See https://no-color.org
+static COLOR_ENABLED: LazyLock<bool> = LazyLock::new(|| {
+    color_enabled(
+        std::env::var_os("NO_COLOR").as_deref(),
+        std::io::stdout().is_terminal(),
+    )
+});
+
+// An empty `NO_COLOR` is the same as not set
+fn color_enabled(no_color: Option<&OsStr>, is_terminal: bool) -> bool {
+    let no_color = no_color.is_some_and(|val| !val.is_empty());
+    !no_color && is_terminal
+}
+
+static TERMINAL_WIDTH: LazyLock<Option<usize>> = LazyLock::new(|| {
+    if let Some((Width(width), _)) = terminal_size() {
+        return Some(width as usize);
+    }
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|cols| cols.parse::<usize>().ok())
+});
+
+#[derive(Debug, Clone, Copy)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const GREEN: Color = Color(0, 200, 83);
+    pub const RED: Color = Color(232, 0, 45);
+    pub const GREY: Color = Color(140, 140, 140);
+
+    fn paint(&self, text: &str) -> String {
+        if *COLOR_ENABLED {
+            format!("\x1b[38;2;{};{};{}m{}\x1b[0m", self.0, self.1, self.2, text)
+        } else {
+            text.to_owned()
+        }
+    }
+}
+
+// Matching on substrings as team names on formula1.com include engine
+// suppliers and sponsors which change every other season
+pub fn team_color(team: &str) -> Option<Color> {
+    let team = team.to_lowercase();
+    let color = if team.contains("racing bulls") || team == "rb" || team.starts_with("rb ") {
+        Color(102, 146, 255)
+    } else if team.contains("red bull") {
+        Color(54, 113, 198)
+    } else if team.contains("ferrari") {
+        Color(232, 0, 45)
+    } else if team.contains("mercedes") {
+        Color(39, 244, 210)
+    } else if team.contains("mclaren") {
+        Color(255, 128, 0)
+    } else if team.contains("aston martin") {
+        Color(34, 153, 113)
+    } else if team.contains("alpine") {
+        Color(255, 135, 188)
+    } else if team.contains("williams") {
+        Color(100, 196, 255)
+    } else if team.contains("sauber") || team.contains("audi") {
+        Color(82, 226, 82)
+    } else if team.contains("haas") {
+        Color(182, 186, 189)
+    } else {
+        return None;
+    };
+    Some(color)
+}
+
+pub fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+// Truncates `text` to at most `width` terminal columns, marking cut text with an ellipsis
+pub fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_owned();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut output = String::new();
+    let mut used = 0;
+    for ch in text.chars() {
+        let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if used + ch_width > width - 1 {
+            break;
+        }
+        used += ch_width;
+        output.push(ch);
+    }
+    output.push(ELLIPSIS);
+    output
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+fn pad(text: &str, width: usize, align: Align) -> (usize, usize) {
+    let fill = width.saturating_sub(display_width(text));
+    match align {
+        Align::Left => (0, fill),
+        Align::Right => (fill, 0),
+        Align::Center => (fill / 2, fill - fill / 2),
+    }
+}
+
+#[derive(Debug)]
+struct Column {
+    header: String,
+    align: Align,
+}
+
+#[derive(Debug)]
+pub struct Cell {
+    text: String,
+    color: Option<Color>,
+}
+
+impl Cell {
+    pub fn colored(text: impl ToString, color: Option<Color>) -> Self {
+        Self {
+            text: text.to_string(),
+            color,
+        }
+    }
+}
+
+impl<T: ToString> From<T> for Cell {
+    fn from(text: T) -> Self {
+        Self::colored(text, None)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Table {
+    title: Option<String>,
+    columns: Vec<Column>,
+    rows: Vec<Vec<Cell>>,
+    footer: Vec<String>,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: impl ToString) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn column(mut self, header: impl ToString, align: Align) -> Self {
+        self.columns.push(Column {
+            header: header.to_string(),
+            align,
+        });
+        self
+    }
+
+    pub fn add_row(&mut self, row: Vec<Cell>) {
+        self.rows.push(row);
+    }
+
+    // Lines printed as is below the table, e.g. legends or notes
+    pub fn add_footer(&mut self, line: impl ToString) {
+        self.footer.push(line.to_string());
+    }
+
+    fn has_header(&self) -> bool {
+        self.columns.iter().any(|c| !c.header.is_empty())
+    }
+
+    fn column_widths(&self) -> Vec<usize> {
+        self.fitted_column_widths(*TERMINAL_WIDTH)
+    }
+
+    fn fitted_column_widths(&self, max_width: Option<usize>) -> Vec<usize> {
+        let mut widths: Vec<usize> = self
+            .columns
+            .iter()
+            .map(|c| display_width(&c.header))
+            .collect();
+        for row in &self.rows {
+            for (idx, cell) in row.iter().enumerate().take(widths.len()) {
+                widths[idx] = widths[idx].max(display_width(&cell.text));
+            }
+        }
+
+        // widening last column so that title fits inside the box
+        if let Some(title) = &self.title {
+            let inner = Self::inner_width(&widths);
+            let title_width = display_width(title);
+            if title_width > inner {
+                if let Some(last) = widths.last_mut() {
+                    *last += title_width - inner;
+                }
+            }
+        }
+
+        // shrinking widest columns until table fits in the terminal
+        if let Some(max_width) = max_width {
+            while Self::inner_width(&widths) + 4 > max_width {
+                let (idx, widest) = match widths.iter().enumerate().max_by_key(|(_, w)| **w) {
+                    Some((idx, w)) => (idx, *w),
+                    None => break,
+                };
+                if widest <= MIN_COLUMN_WIDTH {
+                    break;
+                }
+                widths[idx] = widest - 1;
+            }
+        }
+        widths
+    }
+
+    // Width between the outer `| ` and ` |`
+    fn inner_width(widths: &[usize]) -> usize {
+        widths.iter().sum::<usize>() + widths.len().saturating_sub(1) * 3
+    }
+
+    fn write_border(output: &mut String, widths: &[usize], joint: char) -> Result<()> {
+        let border = widths
+            .iter()
+            .map(|w| "-".repeat(w + 2))
+            .collect::<Vec<_>>()
+            .join(&joint.to_string());
+        writeln!(output, "+{border}+")?;
+        Ok(())
+    }
+
+    fn write_row<'a>(
+        output: &mut String,
+        widths: &[usize],
+        cells: impl Iterator<Item = (&'a str, Option<Color>, Align)>,
+    ) -> Result<()> {
+        let mut line = String::from("|");
+        for ((text, color, align), width) in cells.zip(widths) {
+            let text = truncate(text, *width);
+            let (left, right) = pad(&text, *width, align);
+            let text = match color {
+                Some(color) => color.paint(&text),
+                None => text,
+            };
+            write!(line, " {}{}{} |", " ".repeat(left), text, " ".repeat(right))?;
+        }
+        writeln!(output, "{line}")?;
+        Ok(())
+    }
+
+    pub fn render(&self, output: &mut String) -> Result<()> {
+        let widths = self.column_widths();
+
+        if let Some(title) = &self.title {
+            let inner = Self::inner_width(&widths);
+            Self::write_border(output, &[inner], '-')?;
+            Self::write_row(
+                output,
+                &[inner],
+                [(title.as_str(), None, Align::Center)].into_iter(),
+            )?;
+        }
+        Self::write_border(output, &widths, '+')?;
+
+        if self.has_header() {
+            let headers = self
+                .columns
+                .iter()
+                .map(|c| (c.header.as_str(), None, c.align));
+            Self::write_row(output, &widths, headers)?;
+            Self::write_border(output, &widths, '+')?;
+        }
+
+        for row in &self.rows {
+            let cells = row
+                .iter()
+                .zip(&self.columns)
+                .map(|(cell, c)| (cell.text.as_str(), cell.color, c.align));
+            Self::write_row(output, &widths, cells)?;
+        }
+        if !self.rows.is_empty() {
+            Self::write_border(output, &widths, '+')?;
+        }
+
+        for line in &self.footer {
+            writeln!(output, "{line}")?;
+        }
+        Ok(())
+    }
+
+    // GitHub flavoured Markdown table, title & footer become plain lines. Colors
+    // are dropped and columns are not fitted to the terminal
+    pub fn render_markdown(&self, output: &mut String) -> Result<()> {
+        // a line break ends the table row, so breaks are written as spaces
+        let escape = |text: &str| {
+            text.replace("\r\n", " ")
+                .replace(['\r', '\n'], " ")
+                .replace('|', "\\|")
+        };
+        if let Some(title) = &self.title {
+            writeln!(output, "**{}**\n", escape(title))?;
+        }
+        let headers = self
+            .columns
+            .iter()
+            .map(|c| escape(&c.header))
+            .collect::<Vec<_>>();
+        writeln!(output, "| {} |", headers.join(" | "))?;
+        let aligns = self
+            .columns
+            .iter()
+            .map(|c| match c.align {
+                Align::Left => ":---",
+                Align::Right => "---:",
+                Align::Center => ":---:",
+            })
+            .collect::<Vec<_>>();
+        writeln!(output, "| {} |", aligns.join(" | "))?;
+        for row in &self.rows {
+            let cells = row.iter().map(|c| escape(&c.text)).collect::<Vec<_>>();
+            writeln!(output, "| {} |", cells.join(" | "))?;
+        }
+        if !self.footer.is_empty() {
+            writeln!(output)?;
+        }
+        for line in &self.footer {
+            writeln!(output, "_{}_", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = Table::new()
+            .title("Drivers")
+            .column("Driver", Align::Left)
+            .column("Team", Align::Left)
+            .column("Pts", Align::Right);
+        table.add_row(vec![
+            "Max Verstappen".into(),
+            "Red Bull Racing Honda RBPT".into(),
+            25.into(),
+        ]);
+        table.add_row(vec![
+            "Sergio Pérez".into(),
+            "Red Bull | RB".into(),
+            18.into(),
+        ]);
+        table
+    }
+
+    #[test]
+    fn truncate_marks_cut_text() {
+        assert_eq!(truncate("Verstappen", 10), "Verstappen");
+        assert_eq!(truncate("Verstappen", 6), "Verst…");
+        assert_eq!(truncate("Verstappen", 1), "…");
+        assert_eq!(truncate("Verstappen", 0), "");
+        assert_eq!(truncate("Pérez", 4), "Pér…");
+    }
+
+    #[test]
+    fn truncate_counts_wide_characters_as_two_columns() {
+        // every character takes two terminal columns
+        let text = "鈴鹿サーキット";
+        assert_eq!(display_width(text), 14);
+        assert_eq!(truncate(text, 7), "鈴鹿サ…");
+        assert_eq!(display_width(&truncate(text, 7)), 7);
+        // a wide character never straddles the limit
+        assert_eq!(truncate(text, 6), "鈴鹿…");
+        assert_eq!(display_width(&truncate(text, 6)), 5);
+    }
+
+    #[test]
+    fn pad_fills_by_alignment() {
+        assert_eq!(pad("P1", 6, Align::Left), (0, 4));
+        assert_eq!(pad("P1", 6, Align::Right), (4, 0));
+        assert_eq!(pad("P1", 7, Align::Center), (2, 3));
+        assert_eq!(pad("鈴鹿", 6, Align::Right), (2, 0));
+        assert_eq!(pad("Verstappen", 4, Align::Center), (0, 0));
+    }
+
+    #[test]
+    fn column_widths_fit_terminal() {
+        let table = table();
+        assert_eq!(table.fitted_column_widths(None), vec![14, 26, 3]);
+        // wide enough, nothing to shrink
+        assert_eq!(table.fitted_column_widths(Some(80)), vec![14, 26, 3]);
+        // widest columns shrink first
+        let widths = table.fitted_column_widths(Some(40));
+        assert_eq!(widths, vec![14, 13, 3]);
+        assert_eq!(Table::inner_width(&widths) + 4, 40);
+        // columns never shrink below the minimum, even if the table doesn't fit
+        assert_eq!(table.fitted_column_widths(Some(10)), vec![4, 4, 3]);
+    }
+
+    #[test]
+    fn title_widens_last_column() {
+        let mut table = Table::new()
+            .title("Fastest laps of the season")
+            .column("Lap", Align::Left)
+            .column("Time", Align::Left);
+        table.add_row(vec![1.into(), "1:32.608".into()]);
+        let widths = table.fitted_column_widths(None);
+        assert_eq!(
+            Table::inner_width(&widths),
+            display_width("Fastest laps of the season")
+        );
+    }
+
+    #[test]
+    fn no_color_disables_colors() {
+        assert!(color_enabled(None, true));
+        assert!(!color_enabled(Some(OsStr::new("1")), true));
+        // empty value is ignored per no-color.org
+        assert!(color_enabled(Some(OsStr::new("")), true));
+        // piped output is never colored
+        assert!(!color_enabled(None, false));
+    }
+
+    #[test]
+    fn markdown_escapes_pipes_and_keeps_alignment() {
+        let mut table = table();
+        table.add_footer("Sprint points included");
+        let mut output = String::new();
+        table.render_markdown(&mut output).unwrap();
+        assert_eq!(
+            output,
+            "**Drivers**\n\n\
+             | Driver | Team | Pts |\n\
+             | :--- | :--- | ---: |\n\
+             | Max Verstappen | Red Bull Racing Honda RBPT | 25 |\n\
+             | Sergio Pérez | Red Bull \\| RB | 18 |\n\
+             \n\
+             _Sprint points included_\n"
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+
+use crate::error::Result;
+use crate::h2h::beats;
+use crate::laptime::pp_gap;
+use crate::quali::CompletedQualifying;
+use crate::results::CompletedRace;
+use crate::table::{team_color, Align, Cell, Table};
+use crate::utils::{same_team, QualiPositionInfo};
+
+// Drivers are stored in name order, so the same pairing is always keyed the
+// same irrespective of finishing order
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Pairing {
+    team: String,
+    first: String,
+    second: String,
+}
+
+#[derive(Default)]
+struct Battle {
+    rounds: usize,
+    race: (usize, usize),
+    quali: (usize, usize),
+    points: (usize, usize),
+    quali_gaps: Vec<i64>,
+}
+
+// Gap is taken from the last session both drivers set a time in, as times from
+// different sessions are not comparable. Positive gap means `first` was slower
+fn quali_gap_millis(first: &QualiPositionInfo, second: &QualiPositionInfo) -> Option<i64> {
+    let sessions = [
+        (&first.q3, &second.q3),
+        (&first.q2, &second.q2),
+        (&first.q1, &second.q1),
+    ];
+    for (a, b) in sessions {
+        if let (Some(a), Some(b)) = (a, b) {
+            return Some(a.gap_to(b));
+        }
+    }
+    None
+}
+
+pub fn pp_teammate_battles(
+    races: &[CompletedRace],
+    qualifying: &[CompletedQualifying],
+    output: &mut String,
+) -> Result<()> {
+    let mut rounds: BTreeMap<usize, (Option<&CompletedRace>, Option<&CompletedQualifying>)> =
+        BTreeMap::new();
+    for race in races {
+        rounds.entry(race.round).or_default().0 = Some(race);
+    }
+    for quali in qualifying {
+        rounds.entry(quali.round).or_default().1 = Some(quali);
+    }
+
+    // race & quali pages spell some teams differently, e.g. `Red Bull Racing` &
+    // `Red Bull Racing Honda RBPT`, all spellings are grouped under the shortest
+    let mut team_names = races
+        .iter()
+        .flat_map(|r| r.results.iter().map(|p| &p.team))
+        .chain(
+            qualifying
+                .iter()
+                .flat_map(|q| q.results.iter().map(|p| &p.team)),
+        )
+        .flatten()
+        .map(|team| team.as_str())
+        .collect::<Vec<_>>();
+    team_names.sort_by_key(|team| team.len());
+    let team_name = |team: &str| {
+        team_names
+            .iter()
+            .find(|name| same_team(name, team))
+            .map_or(team.to_owned(), |name| name.to_string())
+    };
+
+    let mut battles: BTreeMap<Pairing, Battle> = BTreeMap::new();
+    for (race, quali) in rounds.values() {
+        // team of each driver for this round, drivers may switch seats mid season
+        let mut teams: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let race_teams = race
+            .iter()
+            .flat_map(|r| r.results.iter().map(|p| (&p.name, &p.team)));
+        let quali_teams = quali
+            .iter()
+            .flat_map(|q| q.results.iter().map(|p| (&p.name, &p.team)));
+        for (name, team) in race_teams.chain(quali_teams) {
+            let Some(team) = team else { continue };
+            let drivers = teams.entry(team_name(team)).or_default();
+            if !drivers.contains(name) {
+                drivers.push(name.clone());
+            }
+        }
+
+        for (team, mut drivers) in teams {
+            drivers.sort();
+            for (idx, first) in drivers.iter().enumerate() {
+                for second in &drivers[idx + 1..] {
+                    let pairing = Pairing {
+                        team: team.clone(),
+                        first: first.clone(),
+                        second: second.clone(),
+                    };
+                    let battle = battles.entry(pairing).or_default();
+                    battle.rounds += 1;
+
+                    let race_result =
+                        |name: &str| race.and_then(|r| r.results.iter().find(|p| p.name == name));
+                    if let (Some(a), Some(b)) = (race_result(first), race_result(second)) {
+                        match beats(a.position, b.position) {
+                            Some(true) => battle.race.0 += 1,
+                            Some(false) => battle.race.1 += 1,
+                            None => {}
+                        }
+                        battle.points.0 += a.points;
+                        battle.points.1 += b.points;
+                    }
+
+                    let quali_result =
+                        |name: &str| quali.and_then(|q| q.results.iter().find(|p| p.name == name));
+                    if let (Some(a), Some(b)) = (quali_result(first), quali_result(second)) {
+                        match beats(a.position, b.position) {
+                            Some(true) => battle.quali.0 += 1,
+                            Some(false) => battle.quali.1 += 1,
+                            None => {}
+                        }
+                        if let Some(gap) = quali_gap_millis(a, b) {
+                            battle.quali_gaps.push(gap);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut table = Table::new()
+        .title("TEAMMATE BATTLES")
+        .column("Team", Align::Left)
+        .column("Drivers", Align::Left)
+        .column("Rounds", Align::Right)
+        .column("Race", Align::Center)
+        .column("Quali", Align::Center)
+        .column("Avg Quali Gap", Align::Right)
+        .column("Points", Align::Center);
+
+    for (pairing, battle) in battles {
+        let avg_gap = if battle.quali_gaps.is_empty() {
+            "-".to_owned()
+        } else {
+            let avg = battle.quali_gaps.iter().sum::<i64>() / battle.quali_gaps.len() as i64;
+            pp_gap(avg)
+        };
+        let color = team_color(&pairing.team);
+        table.add_row(vec![
+            Cell::colored(&pairing.team, color),
+            format!("{} vs {}", pairing.first, pairing.second).into(),
+            battle.rounds.into(),
+            format!("{} - {}", battle.race.0, battle.race.1).into(),
+            format!("{} - {}", battle.quali.0, battle.quali.1).into(),
+            avg_gap.into(),
+            format!("{} - {}", battle.points.0, battle.points.1).into(),
+        ]);
+    }
+
+    if races
+        .iter()
+        .all(|r| r.results.iter().all(|p| p.team.is_none()))
+    {
+        table.add_footer("No team information in cache. Run `f1gp clean` & `f1gp pull` to refetch");
+    } else {
+        table.add_footer("Counts & gaps are from the first driver's point of view");
+    }
+    table.render(output)
+}
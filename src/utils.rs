@@ -1,34 +1,327 @@
+use chrono::{DateTime, Utc};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
 
 use crate::error::{Error, Result};
+use crate::laptime::{deserialize_lenient, LapTime};
+use crate::CURR_YEAR;
 
 pub const TMP_DIR_NAME: &str = "f1_schedule_standings";
 pub const F1_TABLE_SELECTOR: &str = "table.f1-table > tbody > tr";
+pub const F1_BASE_URL: &str = "https://www.formula1.com";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RaceStatus {
+    Finished,
+    Retired,
+    NotClassified,
+    DidNotStart,
+    Disqualified,
+}
+
+impl RaceStatus {
+    // Classified drivers have a race time or gap, e.g. `+5.123s` or `+1 lap`.
+    // Anything else is a status like `DNF` or a retirement reason like `Engine`,
+    // which is returned as the second element
+    pub fn parse(position_text: &str, time_text: &str) -> (Self, Option<String>) {
+        let is_time = time_text.starts_with('+')
+            || time_text.chars().next().is_some_and(|c| c.is_ascii_digit());
+        match (position_text, time_text) {
+            (_, "DNS") => (Self::DidNotStart, None),
+            ("DQ", _) | (_, "DSQ") | (_, "DQ") => (Self::Disqualified, None),
+            (_, "DNF") => (Self::Retired, None),
+            ("NC", _) if is_time || time_text.is_empty() => (Self::NotClassified, None),
+            (_, _) if is_time => (Self::Finished, None),
+            (_, "") => (Self::Finished, None),
+            (_, reason) => (Self::Retired, Some(reason.to_owned())),
+        }
+    }
+}
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PositionInfo {
     pub position: usize,
     pub name: String,
     pub points: usize,
+    // Available for race results & driver standings. Missing in caches created by
+    // older versions
+    pub team: Option<String>,
+    pub status: Option<RaceStatus>,
+    // Retirement reason, only published for some races
+    pub reason: Option<String>,
+    // Only available for driver standings, three letter code e.g. `GBR`
+    pub nationality: Option<String>,
+    // Only available for race results
+    pub number: Option<usize>,
+}
+
+impl PositionInfo {
+    pub fn pp_position(&self) -> String {
+        match self.status {
+            Some(RaceStatus::DidNotStart) => "DNS".to_owned(),
+            Some(RaceStatus::Disqualified) => "DSQ".to_owned(),
+            Some(RaceStatus::NotClassified) => "NC".to_owned(),
+            Some(RaceStatus::Retired) if self.position == 0 => "DNF".to_owned(),
+            // caches of older versions have no status
+            None if self.position == 0 => "NC".to_owned(),
+            _ => self.position.to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct QualiPositionInfo {
     pub position: usize,
     pub name: String,
-    pub q1: Option<String>,
-    pub q2: Option<String>,
-    pub q3: Option<String>,
+    pub team: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    pub q1: Option<LapTime>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    pub q2: Option<LapTime>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    pub q3: Option<LapTime>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum QualiSession {
+    Q1,
+    Q2,
+    Q3,
+}
+
+impl QualiSession {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Q1 => "Q1",
+            Self::Q2 => "Q2",
+            Self::Q3 => "Q3",
+        }
+    }
+}
+
+impl QualiPositionInfo {
+    pub fn time_in(&self, session: QualiSession) -> Option<LapTime> {
+        match session {
+            QualiSession::Q1 => self.q1,
+            QualiSession::Q2 => self.q2,
+            QualiSession::Q3 => self.q3,
+        }
+    }
+}
+
+// Returns value given after `flag`, e.g. `--port 8080` returns `8080`
+pub fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|idx| args.get(idx + 1))
+        .map(|val| val.as_str())
+}
+
+// First argument when it is not a flag, e.g. round in `f1gp result 3 --format csv`
+pub fn round_arg(args: &[String]) -> Option<&str> {
+    args.first()
+        .filter(|arg| !arg.starts_with("--"))
+        .map(|arg| arg.as_str())
+}
+
+pub fn has_flag(args: &[String], flag: &str) -> bool {
+    args.iter().any(|arg| arg == flag)
+}
+
+// Finds the full name matching `query`. A case insensitive exact match is preferred,
+// otherwise the only name containing `query`, e.g. `verstappen` -> `Max Verstappen`.
+// Names can repeat in `names`, e.g. once per round
+pub fn find_driver_name<'a>(names: impl Iterator<Item = &'a str>, query: &str) -> Result<String> {
+    let lower_query = query.to_lowercase();
+    let mut partial_matches: Vec<&str> = Vec::new();
+    for name in names {
+        let lower_name = name.to_lowercase();
+        if lower_name == lower_query {
+            return Ok(name.to_owned());
+        }
+        if lower_name.contains(&lower_query) && !partial_matches.contains(&name) {
+            partial_matches.push(name);
+        }
+    }
+    match partial_matches.as_slice() {
+        [] => Err(Error::UnknownDriver(query.to_owned())),
+        [name] => Ok(name.to_string()),
+        _ => Err(Error::AmbiguousName(
+            query.to_owned(),
+            partial_matches.iter().map(|n| n.to_string()).collect(),
+        )),
+    }
+}
+
+// Finds the team matching `query`. A case insensitive exact match is preferred,
+// then names starting with `query` and then names containing it, e.g. `mercedes`
+// -> `Mercedes` but `mclaren` -> `McLaren Mercedes`
+pub fn find_team_name<'a>(names: impl Iterator<Item = &'a str>, query: &str) -> Result<String> {
+    let lower_query = query.to_lowercase();
+    let mut candidates: Vec<&str> = Vec::new();
+    for name in names {
+        let lower_name = name.to_lowercase();
+        if lower_name == lower_query {
+            return Ok(name.to_owned());
+        }
+        if lower_name.contains(&lower_query) && !candidates.contains(&name) {
+            candidates.push(name);
+        }
+    }
+    let prefix_matches = candidates
+        .iter()
+        .filter(|n| n.to_lowercase().starts_with(&lower_query))
+        .copied()
+        .collect::<Vec<_>>();
+    let mut matches = if prefix_matches.is_empty() {
+        candidates
+    } else {
+        prefix_matches
+    };
+    // spellings of the same team, e.g. `Red Bull Racing` & `Red Bull Racing Honda
+    // RBPT`, are a single match under the shortest name
+    matches.sort_by_key(|n| n.len());
+    let mut teams: Vec<&str> = Vec::new();
+    for name in matches {
+        if !teams.iter().any(|t| same_team(t, name)) {
+            teams.push(name);
+        }
+    }
+    match teams.as_slice() {
+        [] => Err(Error::UnknownTeam(query.to_owned())),
+        [name] => Ok(name.to_string()),
+        _ => Err(Error::AmbiguousName(
+            query.to_owned(),
+            teams.iter().map(|n| n.to_string()).collect(),
+        )),
+    }
+}
+
+// Team names differ slightly between pages, e.g. `Red Bull Racing` & `Red Bull
+// Racing Honda RBPT`. Prefix match keeps `McLaren Mercedes` apart from `Mercedes`
+pub fn same_team(team: &str, other: &str) -> bool {
+    team.starts_with(other) || other.starts_with(team)
+}
+
+// Three letter abbreviation as shown on timing screens, e.g. `Max Verstappen` -> `VER`
+pub fn driver_code(name: &str) -> String {
+    let surname = name.split_whitespace().last().unwrap_or(name);
+    surname.chars().take(3).collect::<String>().to_uppercase()
+}
+
+// Joins all text nodes of a table cell, e.g. team names split across spans
+pub fn cell_text(element: scraper::ElementRef) -> String {
+    element
+        .text()
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Directory for user data which must survive `f1gp clean`, e.g. predictions.
+// Can be overridden with `F1GP_DATA_DIR`
+pub fn data_dir() -> Result<PathBuf> {
+    let data_dir = match std::env::var_os("F1GP_DATA_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => match std::env::var_os("HOME") {
+            Some(home) => PathBuf::from(home).join(".local/share/f1gp"),
+            None => std::env::temp_dir().join("f1gp"),
+        },
+    };
+    if !data_dir.exists() {
+        fs::create_dir_all(&data_dir)?;
+    }
+    Ok(data_dir)
+}
+
+pub struct RoundPage<T> {
+    pub round: usize,
+    pub gp_name: String,
+    pub data: T,
+}
+
+// Fetches `page` (e.g. `starting-grid`) of every Grand Prix listed on the season
+// results page concurrently. Rounds in `existing_round_results` are skipped.
+// Fails when any round fails, after all other rounds finished fetching
+pub fn fetch_round_pages<T: Send + 'static>(
+    html: String,
+    existing_round_results: Vec<usize>,
+    page: &'static str,
+    parse_page: fn(String) -> Result<T>,
+) -> Result<Vec<RoundPage<T>>> {
+    let document = scraper::Html::parse_document(&html);
+    // constructing all selectors
+    let table_selector = scraper::Selector::parse(F1_TABLE_SELECTOR).map_err(|_| Error::Scraper)?;
+    let anchor_selector = scraper::Selector::parse("a").map_err(|_| Error::Scraper)?;
+    let td_selector = scraper::Selector::parse("td").map_err(|_| Error::Scraper)?;
+
+    let mut join_handles: Vec<JoinHandle<Result<()>>> = Vec::new();
+    let output_data = Arc::new(Mutex::new(Vec::new()));
+
+    for (idx, element) in document.select(&table_selector).enumerate() {
+        if existing_round_results.contains(&(idx + 1)) {
+            continue;
+        }
+        let output_arc_clone = output_data.clone();
+
+        let td_link = element
+            .select(&td_selector)
+            .next()
+            .ok_or(Error::ParseRaceResults)?;
+        let link = td_link
+            .select(&anchor_selector)
+            .next()
+            .ok_or(Error::ParseRaceResults)?
+            .value()
+            .attr("href")
+            .ok_or(Error::ParseRaceResults)?;
+        let gp_name = cell_text(td_link);
+        let page_url = format!("{}/{}", F1_BASE_URL, link).replace("race-result", page);
+
+        let handle = std::thread::spawn(move || {
+            println!("Fetching {} data from {}", page, &page_url);
+            let body = ureq::get(&page_url).call()?.into_string()?;
+            let data = parse_page(body)?;
+            let mut guarded_data = output_arc_clone
+                .lock()
+                .map_err(|_| Error::ParseRaceResults)?;
+            guarded_data.push(RoundPage {
+                round: idx + 1,
+                gp_name,
+                data,
+            });
+            Ok(())
+        });
+        join_handles.push(handle);
+    }
+
+    let mut first_err = None;
+    for h in join_handles.into_iter() {
+        if let Err(err) = h.join().map_err(|_| Error::ParseRaceResults)? {
+            first_err.get_or_insert(err);
+        }
+    }
+    if let Some(err) = first_err {
+        return Err(err);
+    }
+
+    let lock = Arc::into_inner(output_data).ok_or(Error::ParseRaceResults)?;
+    let mut output_data = lock.into_inner().map_err(|_| Error::ParseRaceResults)?;
+    output_data.sort_by_key(|r| r.round);
+    Ok(output_data)
 }
 
 pub trait DataFetcher {
     type A;
 
-    fn cache_file_name() -> String;
-    fn resource_url() -> String;
+    // Every season is cached in its own file
+    fn cache_file_name(year: i32) -> String;
+    fn resource_url(year: i32) -> String;
     fn process_data(raw_data: String, file_path: &Path) -> Result<Self::A>;
 
     fn get_or_create_tmp_dir() -> Result<PathBuf> {
@@ -40,8 +333,8 @@ pub trait DataFetcher {
         Ok(tmp_dir)
     }
 
-    fn fetch_internet_resource() -> Result<String> {
-        let body = ureq::get(&Self::resource_url()).call()?.into_string()?;
+    fn fetch_internet_resource(year: i32) -> Result<String> {
+        let body = ureq::get(&Self::resource_url(year)).call()?.into_string()?;
         Ok(body)
     }
 
@@ -65,28 +358,49 @@ pub trait DataFetcher {
         Ok(Self::A::deserialize(&mut schedule)?)
     }
 
-    fn get_cache_file_path() -> Result<PathBuf> {
+    fn get_cache_file_path(year: i32) -> Result<PathBuf> {
         let tmp_dir = Self::get_or_create_tmp_dir()?;
-        let file_name = Self::cache_file_name();
+        let file_name = Self::cache_file_name(year);
         let file_path = tmp_dir.join(file_name);
         Ok(file_path)
     }
 
+    // Current season's data if already cached, never fetches. For data which
+    // only adds detail to another command's output
+    fn get_cached_data() -> Option<Self::A>
+    where
+        Self: Sized,
+        Self::A: DeserializeOwned,
+    {
+        let file_path = Self::get_cache_file_path(*CURR_YEAR).ok()?;
+        if !file_path.exists() {
+            return None;
+        }
+        Self::read_from_cache(&file_path).ok()
+    }
+
+    // Time current season's cache was last written, `None` if not cached yet
+    fn cache_modified() -> Option<DateTime<Utc>> {
+        let file_path = Self::get_cache_file_path(*CURR_YEAR).ok()?;
+        let modified = fs::metadata(file_path).ok()?.modified().ok()?;
+        Some(modified.into())
+    }
+
     // Setting `force_pull` to true will always make a call to
     // the internet resource.
     // If `force_pull` is false, it may or may not fetch from internet
     // resource depending on the existing of local cache
     // TODO: May be split into read_from_cache & fetch_from_internet
     // functions and avoid this confusion
-    fn get_data_internal_with_pull(force_pull: bool) -> Result<Self::A>
+    fn get_data_internal_with_pull(force_pull: bool, year: i32) -> Result<Self::A>
     where
         Self: Sized,
         Self::A: DeserializeOwned,
         Self::A: Serialize,
     {
-        let file_path = Self::get_cache_file_path()?;
+        let file_path = Self::get_cache_file_path(year)?;
         if !file_path.exists() || force_pull {
-            let raw_data = Self::fetch_internet_resource()?;
+            let raw_data = Self::fetch_internet_resource(year)?;
             let data = Self::process_data(raw_data, &file_path)?;
             Self::cache_and_return_data(data, &file_path)
         } else {
@@ -100,7 +414,17 @@ pub trait DataFetcher {
         Self::A: DeserializeOwned,
         Self::A: Serialize,
     {
-        Self::get_data_internal_with_pull(false)
+        Self::get_data_internal_with_pull(false, *CURR_YEAR)
+    }
+
+    // Data of any season, past seasons are fetched once as they no longer change
+    fn get_season_data(year: i32) -> Result<Self::A>
+    where
+        Self: Sized,
+        Self::A: DeserializeOwned,
+        Self::A: Serialize,
+    {
+        Self::get_data_internal_with_pull(false, year)
     }
 
     fn pull() -> Result<()>
@@ -109,7 +433,129 @@ pub trait DataFetcher {
         Self::A: DeserializeOwned,
         Self::A: Serialize,
     {
-        let _ = Self::get_data_internal_with_pull(true)?;
+        let _ = Self::get_data_internal_with_pull(true, *CURR_YEAR)?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn race_status_from_results_page() {
+        let parse = |position, time| RaceStatus::parse(position, time).0;
+        assert_eq!(parse("1", "1:31:44.742"), RaceStatus::Finished);
+        assert_eq!(parse("2", "+22.457s"), RaceStatus::Finished);
+        assert_eq!(parse("18", "+1 lap"), RaceStatus::Finished);
+        assert_eq!(parse("NC", "+12 laps"), RaceStatus::NotClassified);
+        assert_eq!(parse("NC", "DNF"), RaceStatus::Retired);
+        assert_eq!(parse("NC", "DNS"), RaceStatus::DidNotStart);
+        assert_eq!(parse("DQ", "DQ"), RaceStatus::Disqualified);
+        assert_eq!(parse("NC", "DSQ"), RaceStatus::Disqualified);
+        assert_eq!(
+            RaceStatus::parse("NC", "Engine"),
+            (RaceStatus::Retired, Some("Engine".to_owned()))
+        );
+    }
+
+    #[test]
+    fn unclassified_positions_show_status() {
+        let result = |position, status| PositionInfo {
+            position,
+            name: "Lando Norris".to_owned(),
+            points: 0,
+            team: None,
+            status,
+            reason: None,
+            nationality: None,
+            number: None,
+        };
+        assert_eq!(result(3, Some(RaceStatus::Finished)).pp_position(), "3");
+        assert_eq!(result(0, Some(RaceStatus::Retired)).pp_position(), "DNF");
+        assert_eq!(
+            result(0, Some(RaceStatus::DidNotStart)).pp_position(),
+            "DNS"
+        );
+        assert_eq!(
+            result(0, Some(RaceStatus::Disqualified)).pp_position(),
+            "DSQ"
+        );
+        assert_eq!(
+            result(0, Some(RaceStatus::NotClassified)).pp_position(),
+            "NC"
+        );
+        assert_eq!(result(0, None).pp_position(), "NC");
+    }
+
+    const NAMES: [&str; 4] = [
+        "Max Verstappen",
+        "Lando Norris",
+        "Max Verstappen",
+        "Lance Stroll",
+    ];
+
+    #[test]
+    fn driver_name_partial_match() {
+        let name = find_driver_name(NAMES.into_iter(), "norris").unwrap();
+        assert_eq!(name, "Lando Norris");
+        // repeated names are not ambiguous
+        let name = find_driver_name(NAMES.into_iter(), "max").unwrap();
+        assert_eq!(name, "Max Verstappen");
+    }
+
+    #[test]
+    fn driver_name_exact_match_wins() {
+        let names = ["Ralf Schumacher", "Michael Schumacher", "Schumacher"];
+        let name = find_driver_name(names.into_iter(), "schumacher").unwrap();
+        assert_eq!(name, "Schumacher");
+    }
+
+    #[test]
+    fn team_name_prefers_exact_then_prefix() {
+        let names = [
+            "McLaren Mercedes",
+            "Mercedes",
+            "Red Bull Racing Honda RBPT",
+            "Red Bull Racing",
+        ];
+        let find = |query| find_team_name(names.into_iter(), query);
+        assert_eq!(find("mercedes").unwrap(), "Mercedes");
+        assert_eq!(find("mclaren").unwrap(), "McLaren Mercedes");
+        assert_eq!(find("merc").unwrap(), "Mercedes");
+        assert_eq!(find("red bull").unwrap(), "Red Bull Racing");
+        assert_eq!(find("honda").unwrap(), "Red Bull Racing Honda RBPT");
+        assert!(matches!(find("ferrari"), Err(Error::UnknownTeam(_))));
+    }
+
+    #[test]
+    fn team_name_ambiguous() {
+        let names = [
+            "McLaren Mercedes",
+            "Aston Martin Aramco Mercedes",
+            "Ferrari",
+        ];
+        match find_team_name(names.into_iter(), "mercedes") {
+            Err(Error::AmbiguousName(_, names)) => assert_eq!(
+                names,
+                vec!["McLaren Mercedes", "Aston Martin Aramco Mercedes"]
+            ),
+            other => panic!("expected ambiguous name, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn driver_name_ambiguous_or_unknown() {
+        match find_driver_name(NAMES.into_iter(), "lan") {
+            Err(Error::AmbiguousName(query, names)) => {
+                assert_eq!(query, "lan");
+                assert_eq!(names, ["Lando Norris", "Lance Stroll"]);
+            }
+            other => panic!("expected ambiguous match, got {other:?}"),
+        }
+        assert!(matches!(
+            find_driver_name(NAMES.into_iter(), "senna"),
+            Err(Error::UnknownDriver(_))
+        ));
+    }
+}